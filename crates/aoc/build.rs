@@ -0,0 +1,36 @@
+//! Writes the runner's list of days from the `dayNN` crates it depends on, so registering a day
+//! only takes a line in `Cargo.toml`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let manifest: toml::Table = fs::read_to_string(&manifest_path).unwrap().parse().unwrap();
+    let mut days: Vec<&str> = manifest["dependencies"]
+        .as_table()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .filter(|name| {
+            name.strip_prefix("day")
+                .is_some_and(|day| day.len() == 2 && day.parse::<u8>().is_ok())
+        })
+        .collect();
+    days.sort_unstable();
+
+    let list: String = days
+        .iter()
+        .map(|day| format!("    {}::DAY,\n", day))
+        .collect();
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("days.rs"),
+        format!("&[\n{}]\n", list),
+    )
+    .unwrap();
+}
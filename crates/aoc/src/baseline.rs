@@ -0,0 +1,245 @@
+use anyhow::{anyhow, bail, Context, Result};
+use common::{run_day, Day, DayReport, RunOptions};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::client::{default_cache_dir, write_atomically};
+use crate::YEAR;
+
+/// How much slower than its baseline a part may get before it counts as a regression, in percent.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Where this machine's baseline is kept: `~/.cache/aoc/<year>/baseline.tsv`, next to the
+/// downloaded inputs, so that timings from different machines never get compared.
+pub fn default_path() -> PathBuf {
+    default_cache_dir()
+        .join(YEAR.to_string())
+        .join("baseline.tsv")
+}
+
+/// Recorded solve times of each part, one tab separated line per part:
+/// `day part nanoseconds`.
+///
+/// The time of a part is the median over the runs when benchmarking, so baselines recorded with
+/// `--bench` are much less noisy than ones from a single run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    timings: BTreeMap<(u8, u8), Duration>,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, which doesn't have to exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(baseline) => Self::parse(&baseline)
+                .with_context(|| format!("invalid baseline {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    fn parse(baseline: &str) -> Result<Self> {
+        let mut timings = BTreeMap::new();
+        for (i, line) in baseline.lines().enumerate() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, part, nanos] = fields[..] else {
+                bail!("line {}: expected 3 tab separated fields", i + 1);
+            };
+            let nanos: u64 = nanos
+                .parse()
+                .with_context(|| format!("line {}: invalid time", i + 1))?;
+            timings.insert(
+                (
+                    day.parse()
+                        .with_context(|| format!("line {}: invalid day", i + 1))?,
+                    part.parse()
+                        .with_context(|| format!("line {}: invalid part", i + 1))?,
+                ),
+                Duration::from_nanos(nanos),
+            );
+        }
+        Ok(Self { timings })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut baseline = String::new();
+        for (&(day, part), elapsed) in &self.timings {
+            baseline.push_str(&format!("{}\t{}\t{}\n", day, part, elapsed.as_nanos()));
+        }
+        write_atomically(path, &baseline)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.timings.get(&(day, part)).copied()
+    }
+
+    /// Replaces the baseline of every part in `report`, keeping the other parts' baselines.
+    pub fn record(&mut self, report: &DayReport) {
+        for part in &report.parts {
+            self.timings
+                .insert((report.day, part.part), part.timing.stats().median);
+        }
+    }
+}
+
+/// Solves `parts` of each day and records their timings in the baseline at `path`.
+pub fn save(days: &[&Day], parts: &[u8], options: &RunOptions, path: &Path) -> Result<()> {
+    let mut baseline = Baseline::load(path)?;
+    for day in days {
+        let report = run_day(day, parts, options)?;
+        for part in &report.parts {
+            println!(
+                "Day {} part {}: {:?}",
+                report.day,
+                part.part,
+                part.timing.stats().median
+            );
+        }
+        baseline.record(&report);
+    }
+    baseline.save(path)?;
+    println!("Saved baseline to {}", path.display());
+    Ok(())
+}
+
+/// Solves `parts` of each day and compares their timings with the baseline at `path`, returning
+/// the number of parts that got slower by more than `threshold` percent.
+pub fn compare(
+    days: &[&Day],
+    parts: &[u8],
+    options: &RunOptions,
+    path: &Path,
+    threshold: f64,
+) -> Result<usize> {
+    let baseline = Baseline::load(path)?;
+    if baseline.timings.is_empty() {
+        return Err(anyhow!(
+            "no baseline in {}, record one with --save-baseline",
+            path.display()
+        ));
+    }
+
+    let mut reports = vec![];
+    for day in days {
+        reports.push(run_day(day, parts, options)?);
+    }
+    let regressions = write_comparison(&mut io::stdout().lock(), &baseline, &reports, threshold)?;
+    if regressions > 0 {
+        println!(
+            "\n{} part(s) slower than the baseline by more than {}%",
+            regressions, threshold
+        );
+    }
+    Ok(regressions)
+}
+
+/// Prints each part's baseline and current time with the change between them, flagging parts
+/// that got slower by more than `threshold` percent, and returns how many were flagged.
+fn write_comparison(
+    out: &mut impl Write,
+    baseline: &Baseline,
+    reports: &[DayReport],
+    threshold: f64,
+) -> io::Result<usize> {
+    let mut regressions = 0;
+    for report in reports {
+        for part in &report.parts {
+            let now = part.timing.stats().median;
+            let Some(before) = baseline.get(report.day, part.part) else {
+                writeln!(
+                    out,
+                    "Day {} part {}: {:?}, no baseline",
+                    report.day, part.part, now
+                )?;
+                continue;
+            };
+            let change = (now.as_secs_f64() / before.as_secs_f64().max(1e-9) - 1.0) * 100.0;
+            let regressed = change > threshold;
+            if regressed {
+                regressions += 1;
+            }
+            writeln!(
+                out,
+                "Day {} part {}: {:?} -> {:?} ({:+.1}%){}",
+                report.day,
+                part.part,
+                before,
+                now,
+                change,
+                if regressed { " REGRESSION" } else { "" }
+            )?;
+        }
+    }
+    Ok(regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::temp_dir;
+    use common::{PartReport, Timing};
+
+    fn report(day: u8, millis: &[u64]) -> DayReport {
+        DayReport {
+            day,
+            parse: Timing::Single(Duration::ZERO),
+            parse_alloc: None,
+            parse_spans: vec![],
+            parts: millis
+                .iter()
+                .zip(1..)
+                .map(|(&millis, part)| PartReport {
+                    part,
+                    answer: String::new(),
+                    answer_type: "u32",
+                    timing: Timing::Single(Duration::from_millis(millis)),
+                    alloc: None,
+                    spans: vec![],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn saves_and_merges_baselines() {
+        let path = temp_dir("baseline").join("2021").join("baseline.tsv");
+        let mut baseline = Baseline::load(&path).unwrap();
+        baseline.record(&report(1, &[5, 7]));
+        baseline.record(&report(12, &[40]));
+        baseline.save(&path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "1\t1\t5000000\n1\t2\t7000000\n12\t1\t40000000\n"
+        );
+
+        let mut baseline = Baseline::load(&path).unwrap();
+        baseline.record(&report(1, &[6]));
+        assert_eq!(baseline.get(1, 1), Some(Duration::from_millis(6)));
+        assert_eq!(baseline.get(1, 2), Some(Duration::from_millis(7)));
+        assert!(Baseline::parse("1\t1\n").is_err());
+    }
+
+    #[test]
+    fn flags_parts_slower_than_the_threshold() {
+        let mut baseline = Baseline::default();
+        baseline.record(&report(1, &[10, 10]));
+        let mut out = vec![];
+        let regressions = write_comparison(
+            &mut out,
+            &baseline,
+            &[report(1, &[11, 12]), report(2, &[3])],
+            15.0,
+        )
+        .unwrap();
+        assert_eq!(regressions, 1);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Day 1 part 1: 10ms -> 11ms (+10.0%)\n\
+             Day 1 part 2: 10ms -> 12ms (+20.0%) REGRESSION\n\
+             Day 2 part 1: 3ms, no baseline\n"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::{bail, Result};
+use common::{diff, run_day, Answers, Day, RunOptions};
+
+/// Answers for the real inputs, from the workspace's `answers.toml`.
+pub const ANSWERS: &str = include_str!("../../../answers.toml");
+
+/// Solves `parts` of each day from its real input and compares the answers with `answers`,
+/// failing if any of them changed. A day that fails to parse or solve counts its parts as failed
+/// and the other days are still checked.
+pub fn check(days: &[&Day], parts: &[u8], options: &RunOptions, answers: &Answers) -> Result<()> {
+    let mut changed = 0;
+    let mut failed = 0;
+
+    for day in days {
+        let report = match run_day(day, parts, options) {
+            Ok(report) => report,
+            Err(err) => {
+                failed += parts.len();
+                println!("Day {}: failed: {:#}", day.number, err);
+                continue;
+            }
+        };
+        for part in &report.parts {
+            match answers.get(report.day, part.part) {
+                Some(expected) if expected == part.answer => {
+                    println!("Day {} part {}: ok", report.day, part.part);
+                }
+                Some(expected) => {
+                    changed += 1;
+                    println!("Day {} part {}: answer changed", report.day, part.part);
+                    print!("{}", diff(expected, &part.answer));
+                }
+                None => {
+                    println!(
+                        "Day {} part {}: no known answer, got {}",
+                        report.day, part.part, part.answer
+                    );
+                }
+            }
+        }
+    }
+
+    match (changed, failed) {
+        (0, 0) => Ok(()),
+        (changed, 0) => bail!("{} answer(s) changed", changed),
+        (0, failed) => bail!("{} part(s) failed", failed),
+        (changed, failed) => bail!("{} answer(s) changed, {} part(s) failed", changed, failed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{AocError, AocResult, Solution};
+
+    /// Answers its part number, or has no solution for part 2 of an empty input.
+    struct Parts;
+
+    impl Solution for Parts {
+        type Input = bool;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> AocResult<bool> {
+            Ok(input.is_empty())
+        }
+
+        fn part1(_: &bool) -> AocResult<u32> {
+            Ok(1)
+        }
+
+        fn part2(&empty: &bool) -> AocResult<u32> {
+            if empty {
+                Err(AocError::no_solution("nothing to find"))
+            } else {
+                Ok(2)
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_checking_after_a_failed_day() {
+        let unsolvable = Day::new::<Parts>(1, "");
+        let solvable = Day::new::<Parts>(2, "input\n");
+        let answers: Answers = "[day01]\npart1 = 1\n\n[day02]\npart1 = 1\npart2 = 3\n"
+            .parse()
+            .unwrap();
+        let err = check(
+            &[&unsolvable, &solvable],
+            &[1, 2],
+            &RunOptions::default(),
+            &answers,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "1 answer(s) changed, 2 part(s) failed");
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the site's operators can tell where automated traffic comes from.
+const USER_AGENT: &str = "github.com/thallada/advent-of-code-2021 aoc runner";
+
+/// Minimum time between two requests to the server, shared by every run that uses the same
+/// cache directory.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The default directory inputs are cached in: `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`.
+pub fn default_cache_dir() -> PathBuf {
+    match env::var_os("XDG_CACHE_HOME") {
+        Some(cache_home) => PathBuf::from(cache_home).join("aoc"),
+        None => PathBuf::from(env::var_os("HOME").unwrap_or_default())
+            .join(".cache")
+            .join("aoc"),
+    }
+}
+
+/// Client for the Advent of Code website, authenticated with a session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, cache_dir: &Path) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: cache_dir.to_path_buf(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Where the input for `year`/`day` is cached.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Returns the input for `year`/`day`, downloading it only if it isn't cached yet.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.input_path(year, day);
+        if path.exists() {
+            return fs::read_to_string(&path)
+                .with_context(|| format!("failed to read cached input {}", path.display()));
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = self.send(|agent| {
+            agent
+                .get(&url)
+                .header("Cookie", &format!("session={}", self.session))
+                .call()
+        })?;
+
+        write_atomically(&path, &input)?;
+        Ok(input)
+    }
+
+    /// Where every answer submitted for `year` is logged.
+    pub fn submissions_path(&self, year: u16) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join("submissions.tsv")
+    }
+
+    /// Posts `answer` for `year`/`day`/`part` and returns the response page.
+    pub fn post_answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        self.send(|agent| {
+            agent
+                .post(&url)
+                .header("Cookie", &format!("session={}", self.session))
+                .send_form([("level", level.as_str()), ("answer", answer)])
+        })
+    }
+
+    /// Sends a request once the rate limit allows it and returns the body of a successful
+    /// response.
+    pub(crate) fn send(
+        &self,
+        request: impl FnOnce(&Agent) -> Result<Response<Body>, ureq::Error>,
+    ) -> Result<String> {
+        self.wait_for_rate_limit()?;
+
+        let mut response = request(&self.agent)?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        match status {
+            200 => Ok(body),
+            400 => bail!(
+                "the server rejected the request, is the session token valid? {}",
+                body.trim()
+            ),
+            429 => bail!("the server is rate limiting requests, try again later"),
+            _ => bail!("request failed with status {}: {}", status, body.trim()),
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request made with this cache
+    /// directory, then records the current request.
+    fn wait_for_rate_limit(&self) -> Result<()> {
+        let stamp = self.cache_dir.join("last-request");
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+
+        if let Some(last) = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis)
+        {
+            let next = last + self.min_interval;
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&stamp, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// Writes `contents` through a temporary file so an interrupted download never leaves a
+/// truncated file behind.
+pub(crate) fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::server::MockServer;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    /// A fresh, empty directory under the system temp directory.
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!(
+            "aoc-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn server() -> MockServer {
+        let mut inputs = HashMap::new();
+        inputs.insert((2021, 1), "199\n200\n".to_string());
+        inputs.insert((2021, 2), "forward 5\n".to_string());
+        MockServer::start("127.0.0.1:0", "secret", inputs, HashMap::new()).unwrap()
+    }
+
+    #[test]
+    fn fetches_and_caches_inputs() {
+        let server = server();
+        let cache_dir = temp_dir("fetch");
+        let client =
+            Client::new(&server.url(), "secret", &cache_dir).with_min_interval(Duration::ZERO);
+
+        assert_eq!(client.fetch_input(2021, 1).unwrap(), "199\n200\n");
+        assert_eq!(client.fetch_input(2021, 1).unwrap(), "199\n200\n");
+        assert_eq!(server.requests(), 1);
+        assert_eq!(
+            fs::read_to_string(cache_dir.join("2021").join("day01.txt")).unwrap(),
+            "199\n200\n"
+        );
+
+        let client = Client::new(&server.url(), "wrong", &temp_dir("fetch"));
+        assert!(client.fetch_input(2021, 1).is_err());
+        assert!(!client.input_path(2021, 1).exists());
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let server = server();
+        let client = Client::new(&server.url(), "secret", &temp_dir("rate-limit"))
+            .with_min_interval(Duration::from_millis(300));
+
+        let now = Instant::now();
+        client.fetch_input(2021, 1).unwrap();
+        client.fetch_input(2021, 2).unwrap();
+        assert!(now.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,416 @@
+use anyhow::Result;
+use common::viz::Recording;
+use common::{run_day, Day, InputSource, RunOptions};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::parallel::answer_cell;
+
+/// How often the screen is redrawn, and how long each frame of a playing visualization is shown.
+const TICK: Duration = Duration::from_millis(100);
+
+const HELP: &str = "↑/↓ select  1/2 run part  enter run both  e switch input  v visualize  q quit";
+const VIEWER_HELP: &str = "←/→ step  space play/pause  home/end first/last  esc back";
+
+/// The input a day is run on: the embedded real input or one of the examples next to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InputChoice {
+    Real,
+    Example(PathBuf),
+}
+
+impl InputChoice {
+    fn label(&self) -> String {
+        match self {
+            InputChoice::Real => "real".to_string(),
+            InputChoice::Example(path) => path
+                .file_name()
+                .map_or(String::new(), |name| name.to_string_lossy().into_owned()),
+        }
+    }
+
+    fn options(&self) -> RunOptions {
+        RunOptions {
+            input: match self {
+                InputChoice::Real => None,
+                InputChoice::Example(path) => Some(InputSource::File(path.clone())),
+            },
+            ..RunOptions::default()
+        }
+    }
+}
+
+/// The outcome of the last run of a part: its answer and solve time, or why it failed.
+type PartResult = Result<(String, Duration), String>;
+
+struct DayEntry {
+    day: &'static Day,
+    inputs: Vec<InputChoice>,
+    input: usize,
+    parts: [Option<PartResult>; 2],
+}
+
+/// A visualization being stepped through.
+struct Viewer {
+    title: String,
+    recording: Recording,
+    frame: usize,
+    playing: bool,
+}
+
+/// The state of the dashboard, separate from the terminal so it can be driven by tests.
+pub struct App {
+    days: Vec<DayEntry>,
+    selected: usize,
+    viewer: Option<Viewer>,
+    status: String,
+    quit: bool,
+}
+
+/// The example inputs of a day, `crates/dayNN/src/input/*.txt` other than the real input, in
+/// name order.
+fn find_examples(workspace: &Path, day: u8) -> Vec<PathBuf> {
+    let dir = workspace
+        .join("crates")
+        .join(format!("day{:02}", day))
+        .join("src")
+        .join("input");
+    let mut examples: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|extension| extension == "txt")
+                && path.file_name().is_some_and(|name| name != "input.txt")
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+impl App {
+    pub fn new(days: &'static [Day], workspace: &Path) -> Self {
+        let days = days
+            .iter()
+            .map(|day| DayEntry {
+                day,
+                inputs: std::iter::once(InputChoice::Real)
+                    .chain(
+                        find_examples(workspace, day.number)
+                            .into_iter()
+                            .map(InputChoice::Example),
+                    )
+                    .collect(),
+                input: 0,
+                parts: [None, None],
+            })
+            .collect();
+        Self {
+            days,
+            selected: 0,
+            viewer: None,
+            status: String::new(),
+            quit: false,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        if self.viewer.is_some() {
+            self.handle_viewer_key(key);
+            return;
+        }
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.days.len().saturating_sub(1))
+            }
+            KeyCode::Char('1') => self.run(&[1]),
+            KeyCode::Char('2') => self.run(&[2]),
+            KeyCode::Enter | KeyCode::Char('r') => self.run(&[1, 2]),
+            KeyCode::Char('e') => {
+                if let Some(entry) = self.days.get_mut(self.selected) {
+                    entry.input = (entry.input + 1) % entry.inputs.len();
+                    entry.parts = [None, None];
+                    self.status = format!(
+                        "Day {} now runs on the {} input",
+                        entry.day.number,
+                        entry.inputs[entry.input].label()
+                    );
+                }
+            }
+            KeyCode::Char('v') => self.open_viewer(),
+            _ => {}
+        }
+    }
+
+    fn handle_viewer_key(&mut self, key: KeyCode) {
+        let Some(viewer) = &mut self.viewer else {
+            return;
+        };
+        let last = viewer.recording.frames().len().saturating_sub(1);
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => self.viewer = None,
+            KeyCode::Left | KeyCode::Char('h') => {
+                viewer.playing = false;
+                viewer.frame = viewer.frame.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                viewer.playing = false;
+                viewer.frame = (viewer.frame + 1).min(last);
+            }
+            KeyCode::Home => viewer.frame = 0,
+            KeyCode::End => viewer.frame = last,
+            KeyCode::Char(' ') => viewer.playing = !viewer.playing,
+            _ => {}
+        }
+    }
+
+    /// Advances a playing visualization by a frame, stopping at the last one.
+    pub fn tick(&mut self) {
+        if let Some(viewer) = &mut self.viewer {
+            if viewer.playing {
+                if viewer.frame + 1 < viewer.recording.frames().len() {
+                    viewer.frame += 1;
+                } else {
+                    viewer.playing = false;
+                }
+            }
+        }
+    }
+
+    fn run(&mut self, parts: &[u8]) {
+        let Some(entry) = self.days.get_mut(self.selected) else {
+            return;
+        };
+        let input = &entry.inputs[entry.input];
+        match run_day(entry.day, parts, &input.options()) {
+            Ok(report) => {
+                for part in report.parts {
+                    entry.parts[usize::from(part.part - 1)] =
+                        Some(Ok((part.answer, part.timing.stats().mean)));
+                }
+                self.status = format!(
+                    "Ran day {} on the {} input, parsed in {:?}",
+                    entry.day.number,
+                    input.label(),
+                    report.parse.stats().mean
+                );
+            }
+            Err(err) => {
+                for &part in parts {
+                    entry.parts[usize::from(part - 1)] = Some(Err(format!("{:#}", err)));
+                }
+                self.status = format!("Day {} failed: {:#}", entry.day.number, err);
+            }
+        }
+    }
+
+    fn open_viewer(&mut self) {
+        let Some(entry) = self.days.get(self.selected) else {
+            return;
+        };
+        let input = &entry.inputs[entry.input];
+        let recording = input
+            .options()
+            .load_input(entry.day.input)
+            .and_then(|raw_input| entry.day.parse(&raw_input))
+            .and_then(|parsed| entry.day.visualize(parsed.as_ref()));
+        match recording {
+            Ok(Some(recording)) if !recording.frames().is_empty() => {
+                self.viewer = Some(Viewer {
+                    title: format!("Day {} ({} input)", entry.day.number, input.label()),
+                    recording,
+                    frame: 0,
+                    playing: false,
+                });
+            }
+            Ok(_) => self.status = format!("Day {} has nothing to visualize", entry.day.number),
+            Err(err) => self.status = format!("Day {} failed: {:#}", entry.day.number, err),
+        }
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [main, status, help] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        match &self.viewer {
+            Some(viewer) => {
+                frame.render_widget(viewer_paragraph(viewer), main);
+                frame.render_widget(Line::from(VIEWER_HELP).style(Color::DarkGray), help);
+            }
+            None => {
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                frame.render_stateful_widget(self.table(), main, &mut state);
+                frame.render_widget(Line::from(HELP).style(Color::DarkGray), help);
+            }
+        }
+        frame.render_widget(Line::from(self.status.as_str()), status);
+    }
+
+    fn table(&self) -> Table<'_> {
+        let rows = self.days.iter().map(|entry| {
+            let mut cells = vec![
+                entry.day.number.to_string(),
+                entry.inputs[entry.input].label(),
+            ];
+            for part in &entry.parts {
+                let (answer, time) = match part {
+                    None => (String::new(), String::new()),
+                    Some(Ok((answer, time))) => (answer_cell(answer), format!("{:?}", time)),
+                    Some(Err(_)) => ("FAILED".to_string(), String::new()),
+                };
+                cells.push(answer);
+                cells.push(time);
+            }
+            Row::new(cells)
+        });
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+            Constraint::Length(12),
+        ];
+        Table::new(rows, widths)
+            .header(
+                Row::new(["Day", "Input", "Part 1", "Time", "Part 2", "Time"])
+                    .style(Style::new().add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .block(Block::bordered().title(" Advent of Code 2021 "))
+    }
+}
+
+fn viewer_paragraph(viewer: &Viewer) -> Paragraph<'_> {
+    let frames = viewer.recording.frames();
+    let frame = &frames[viewer.frame];
+    let lines: Vec<Line> = frame
+        .cells
+        .rows()
+        .map(|row| {
+            Line::from(
+                row.iter()
+                    .map(|cell| {
+                        let style = match cell.color {
+                            Some(common::viz::Rgb(r, g, b)) => Style::new().fg(Color::Rgb(r, g, b)),
+                            None => Style::new(),
+                        };
+                        Span::styled(cell.glyph.to_string(), style)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    Paragraph::new(lines).block(Block::bordered().title(format!(
+        " {}: {} ({}/{}){} ",
+        viewer.title,
+        frame.caption,
+        viewer.frame + 1,
+        frames.len(),
+        if viewer.playing { " playing" } else { "" }
+    )))
+}
+
+/// Runs the dashboard until it is quit, restoring the terminal afterwards even if it failed.
+pub fn dashboard(days: &'static [Day], workspace: &Path) -> Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let result = run_app(&mut terminal, App::new(days, workspace));
+    ratatui::restore();
+    result
+}
+
+fn run_app(terminal: &mut DefaultTerminal, mut app: App) -> Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| app.draw(frame))?;
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key.code);
+                }
+            }
+        } else {
+            app.tick();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{workspace_dir, DAYS};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(usize::from(buffer.area.width))
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn runs_parts_on_the_selected_input() {
+        let mut app = App::new(DAYS, &workspace_dir());
+        assert_eq!(
+            app.days[11]
+                .inputs
+                .iter()
+                .map(InputChoice::label)
+                .collect::<Vec<_>>(),
+            vec!["real", "test1.txt", "test2.txt", "test3.txt"]
+        );
+
+        app.handle_key(KeyCode::Char('e'));
+        app.handle_key(KeyCode::Enter);
+        let answers: Vec<Option<String>> = app.days[0]
+            .parts
+            .iter()
+            .map(|part| part.clone().and_then(Result::ok).map(|(answer, _)| answer))
+            .collect();
+        assert_eq!(answers, vec![Some("7".to_string()), Some("5".to_string())]);
+        assert!(screen(&app).contains("test.txt"));
+
+        app.handle_key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn steps_through_visualizations() {
+        let mut app = App::new(DAYS, &workspace_dir());
+        app.handle_key(KeyCode::Char('v'));
+        assert!(app.viewer.is_none());
+        assert_eq!(app.status, "Day 1 has nothing to visualize");
+
+        app.selected = 12;
+        app.handle_key(KeyCode::Char('e'));
+        app.handle_key(KeyCode::Char('v'));
+        assert!(screen(&app).contains("Day 13 (test.txt input): Unfolded (1/3)"));
+        app.handle_key(KeyCode::End);
+        let last = screen(&app);
+        assert!(last.contains("fold along x=5 (3/3)"));
+        assert!(last.contains("#####"));
+
+        app.handle_key(KeyCode::Esc);
+        assert!(app.viewer.is_none());
+        assert!(!app.quit);
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use common::parse_duration;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::client::{self, default_cache_dir, write_atomically, Client};
+use crate::{workspace_dir, YEAR};
+
+/// Where and as whom to talk to the Advent of Code website.
+#[derive(Debug, clap::Args)]
+pub struct SiteArgs {
+    /// Session cookie of a logged in Advent of Code account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+
+    /// Base URL of the Advent of Code website
+    #[arg(long, value_name = "URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Directory that downloads are cached in [default: ~/.cache/aoc]
+    #[arg(long, value_name = "PATH")]
+    cache_dir: Option<PathBuf>,
+
+    /// Minimum time between two requests to the server [default: 5s]
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    min_interval: Option<Duration>,
+}
+
+impl SiteArgs {
+    pub fn client(&self) -> Client {
+        let cache_dir = self.cache_dir.clone().unwrap_or_else(default_cache_dir);
+        Client::new(&self.base_url, &self.session, &cache_dir)
+            .with_min_interval(self.min_interval.unwrap_or(client::DEFAULT_MIN_INTERVAL))
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct FetchArgs {
+    /// Day to fetch the input of
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Puzzle year
+    #[arg(short, long, default_value_t = YEAR)]
+    year: u16,
+
+    /// Also copy the input to PATH [default: the day crate's src/input/input.txt, if the crate
+    /// exists]
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+pub fn fetch(args: &FetchArgs) -> Result<()> {
+    let client = args.site.client();
+    let input = client.fetch_input(args.year, args.day)?;
+    println!(
+        "Cached input at {}",
+        client.input_path(args.year, args.day).display()
+    );
+
+    let day_dir = workspace_dir()
+        .join("crates")
+        .join(format!("day{:02}", args.day));
+    let output = match &args.output {
+        Some(output) => output.clone(),
+        None if args.year == YEAR && day_dir.exists() => day_dir.join("src/input/input.txt"),
+        None => return Ok(()),
+    };
+
+    copy_input(&output, &input)
+}
+
+/// Copies the downloaded `input` to `output`, unless it would replace a different input. An
+/// empty file, like the one `aoc new` leaves for the input, counts as missing.
+pub(crate) fn copy_input(output: &Path, input: &str) -> Result<()> {
+    match fs::read_to_string(output) {
+        Ok(existing) if existing == input => {}
+        Ok(existing) if !existing.is_empty() => println!(
+            "Not overwriting {}, it differs from the downloaded input",
+            output.display()
+        ),
+        _ => {
+            write_atomically(output, input)?;
+            println!("Wrote {}", output.display());
+        }
+    }
+    Ok(())
+}
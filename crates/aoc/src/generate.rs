@@ -0,0 +1,40 @@
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::find_day;
+
+#[derive(Debug, clap::Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+
+    /// How big the input is; what it counts depends on the day, e.g. readings for day 1 or the
+    /// side of the map for day 15
+    #[arg(short, long)]
+    size: usize,
+
+    /// Seed for the random numbers, so that the same input can be generated again
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Write the input to PATH instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+/// Writes a generated input for the selected day, to run or benchmark it with `--input`.
+pub fn generate(args: &GenerateArgs) -> Result<()> {
+    let day = find_day(args.day)?;
+    let input = day
+        .generate(args.size, args.seed)
+        .ok_or_else(|| anyhow!("day {} has no input generator", day.number))?;
+    match &args.output {
+        Some(path) => {
+            fs::write(path, input).with_context(|| format!("failed to write {}", path.display()))
+        }
+        None => Ok(io::stdout().lock().write_all(input.as_bytes())?),
+    }
+}
@@ -0,0 +1,228 @@
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, Subcommand};
+use common::{run_day_on, write_csv, write_json, write_text, Answers, Day, Format, RunOptions};
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+mod baseline;
+mod check;
+mod client;
+mod dashboard;
+mod fetch;
+mod generate;
+mod parallel;
+mod scaffold;
+mod server;
+mod submit;
+
+/// The year these solutions are for.
+pub const YEAR: u16 = 2021;
+
+/// Every registered day, in order: one for each `dayNN` crate in the runner's dependencies,
+/// listed by the build script. `aoc new` adds new day crates to `Cargo.toml`.
+const DAYS: &[Day] = include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Runs Advent of Code 2021 solutions.
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part of the day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every registered day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Check the answers for the real inputs against the known answers, failing if any changed
+    #[arg(long, conflicts_with_all = ["format", "input"])]
+    check: bool,
+
+    /// Known answers for --check [default: the workspace's answers.toml]
+    #[arg(long, value_name = "PATH", requires = "check")]
+    answers: Option<PathBuf>,
+
+    /// Run every selected part at the same time and print a summary table
+    #[arg(long, conflicts_with_all = ["format", "input", "check"])]
+    parallel: bool,
+
+    /// Threads to run parts on with --parallel [default: one per CPU]
+    #[arg(short, long, value_name = "N", requires = "parallel")]
+    jobs: Option<NonZeroUsize>,
+
+    /// Record the timing of every selected part in this machine's baseline
+    #[arg(long, conflicts_with_all = ["format", "input", "check", "parallel", "compare"])]
+    save_baseline: bool,
+
+    /// Compare the timing of every selected part with this machine's baseline
+    #[arg(long, conflicts_with_all = ["format", "input", "check", "parallel"])]
+    compare: bool,
+
+    /// Compare with the baseline and fail if any part got slower than the threshold
+    #[arg(long, conflicts_with_all = ["format", "input", "check", "parallel", "save_baseline"])]
+    fail_on_regression: bool,
+
+    /// How much slower than its baseline a part may get before it is flagged, in percent
+    #[arg(long, value_name = "PERCENT", default_value_t = baseline::DEFAULT_THRESHOLD)]
+    threshold: f64,
+
+    /// Baseline timings file [default: ~/.cache/aoc/2021/baseline.tsv]
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Browse, run and visualize the registered days in an interactive terminal dashboard
+    Dashboard,
+    /// Create the crate for a new day from the template and register it with the runner
+    New(scaffold::NewArgs),
+    /// Generate a random input of any size for a day, to stress test or benchmark its solution
+    Generate(generate::GenerateArgs),
+    /// Download a day's puzzle input, caching it so it is never downloaded twice
+    Fetch(fetch::FetchArgs),
+    /// Submit an answer, refusing answers already known to be wrong
+    Submit(submit::SubmitArgs),
+    /// Serve the registered days' inputs and answers as a local stand-in for the Advent of Code
+    /// website
+    MockServer(server::MockServerArgs),
+}
+
+/// The root of the workspace this binary was built from.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn find_day(number: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| anyhow!("day {} is not registered", number))
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match &args.command {
+        Some(Command::Dashboard) => return dashboard::dashboard(DAYS, &workspace_dir()),
+        Some(Command::New(new_args)) => return scaffold::new(new_args),
+        Some(Command::Fetch(fetch_args)) => return fetch::fetch(fetch_args),
+        Some(Command::Generate(generate_args)) => return generate::generate(generate_args),
+        Some(Command::Submit(submit_args)) => return submit::submit(submit_args),
+        Some(Command::MockServer(server_args)) => {
+            return server::serve(server_args, DAYS, &check::ANSWERS.parse()?)
+        }
+        None => {}
+    }
+
+    if args.all && args.options.input.is_some() {
+        bail!("--input can only be used with a single --day");
+    }
+
+    let days = match args.day {
+        Some(number) => vec![find_day(number)?],
+        None => DAYS.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if args.check {
+        let answers = match &args.answers {
+            Some(path) => Answers::load(path)?,
+            None => check::ANSWERS.parse()?,
+        };
+        return check::check(&days, &parts, &args.options, &answers);
+    }
+
+    let baseline_path = args.baseline.clone().unwrap_or_else(baseline::default_path);
+    if args.save_baseline {
+        return baseline::save(&days, &parts, &args.options, &baseline_path);
+    }
+    if args.compare || args.fail_on_regression {
+        let regressions =
+            baseline::compare(&days, &parts, &args.options, &baseline_path, args.threshold)?;
+        if args.fail_on_regression && regressions > 0 {
+            bail!("{} part(s) regressed", regressions);
+        }
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout().lock();
+
+    if args.parallel {
+        let jobs = match args.jobs {
+            Some(jobs) => jobs,
+            None => thread::available_parallelism()?,
+        };
+        let (runs, elapsed) = parallel::run_parallel(&days, &parts, &args.options, jobs.get());
+        parallel::write_summary(&mut stdout, &runs, elapsed)?;
+        let failed = runs.iter().filter(|run| run.result.is_err()).count();
+        if failed > 0 {
+            bail!("{} part(s) failed", failed);
+        }
+        return Ok(());
+    }
+
+    if args.format != Format::Text
+        && args.options.viz.format.is_some()
+        && args.options.viz.viz_output.is_none()
+    {
+        bail!("--viz needs a --viz-output file with --format json or csv");
+    }
+
+    let several_days = days.len() > 1;
+    let mut reports = vec![];
+    for (i, day) in days.into_iter().enumerate() {
+        // Loaded once for both the run and the visualization, as stdin can only be read once.
+        let raw_input = args.options.load_input(day.input)?;
+        let report = run_day_on(day, &raw_input, &parts, &args.options)?;
+        if args.format == Format::Text {
+            if i > 0 {
+                println!();
+            }
+            write_text(&mut stdout, &report)?;
+        }
+        if args.options.viz.format.is_some() {
+            let input = day.parse(&raw_input)?;
+            // Each day gets its own file, or they would overwrite each other's.
+            let viz = if several_days {
+                args.options.viz.for_day(day.number)
+            } else {
+                args.options.viz.clone()
+            };
+            match day.visualize(input.as_ref())? {
+                Some(recording) => viz.show(&recording)?,
+                None => eprintln!("Day {} has nothing to visualize", day.number),
+            }
+        }
+        reports.push(report);
+    }
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => write_json(&mut stdout, &reports)?,
+        Format::Csv => write_csv(&mut stdout, &reports)?,
+    }
+
+    Ok(())
+}
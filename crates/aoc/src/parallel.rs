@@ -0,0 +1,226 @@
+use anyhow::{anyhow, Result};
+use common::{run_day, Day, DayReport, RunOptions};
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One part of one day, run as a job of its own.
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    /// The day's report with just this part, or why parsing or solving failed.
+    pub result: Result<DayReport>,
+}
+
+/// Runs every part of every day in `days` as a separate job on a pool of `jobs` threads,
+/// returning the runs in day and part order along with the wall-clock time of the whole run.
+///
+/// Each job parses its own copy of the input, so parts of the same day don't wait on each other.
+/// A part that panics is reported as failed instead of taking the other jobs down with it.
+pub fn run_parallel(
+    days: &[&Day],
+    parts: &[u8],
+    options: &RunOptions,
+    jobs: usize,
+) -> (Vec<PartRun>, Duration) {
+    let queue: Vec<(&Day, u8)> = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(queue.len()));
+
+    let started = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, queue.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&(day, part)) = queue.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result =
+                        panic::catch_unwind(AssertUnwindSafe(|| run_day(day, &[part], options)))
+                            .unwrap_or_else(|payload| {
+                                Err(anyhow!("panicked: {}", panic_message(payload.as_ref())))
+                            });
+                    runs.lock().unwrap().push(PartRun {
+                        day: day.number,
+                        part,
+                        result,
+                    });
+                }
+            });
+        }
+    });
+    let elapsed = started.elapsed();
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| (run.day, run.part));
+    (runs, elapsed)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Multi-line answers, such as letters drawn in `#`s, are summarized by their line count to
+/// keep the table to one row per part.
+pub fn answer_cell(answer: &str) -> String {
+    let lines = answer.trim().lines().count();
+    if lines > 1 {
+        format!("<{} lines>", lines)
+    } else {
+        answer.trim().to_string()
+    }
+}
+
+/// Prints one row per run with its answer and timings, then the totals and every error.
+pub fn write_summary(out: &mut impl Write, runs: &[PartRun], elapsed: Duration) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    )?;
+
+    let mut total = Duration::ZERO;
+    let mut errors = vec![];
+    for run in runs {
+        match &run.result {
+            Ok(report) => {
+                let parse = report.parse.stats().mean;
+                let solve = report
+                    .parts
+                    .iter()
+                    .map(|part| part.timing.stats().mean)
+                    .sum();
+                total += parse + solve;
+                let answer = report
+                    .parts
+                    .first()
+                    .map_or(String::new(), |part| answer_cell(&part.answer));
+                writeln!(
+                    out,
+                    "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}",
+                    run.day,
+                    run.part,
+                    answer,
+                    format!("{:?}", parse),
+                    format!("{:?}", solve)
+                )?;
+            }
+            Err(err) => {
+                writeln!(out, "{:>3}  {:>4}  FAILED", run.day, run.part)?;
+                errors.push((run, err));
+            }
+        }
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{} part(s), {} failed, in {:?} ({:?} of parsing and solving)",
+        runs.len(),
+        errors.len(),
+        elapsed,
+        total
+    )?;
+    for (run, err) in errors {
+        writeln!(out, "Day {} part {}: {:#}", run.day, run.part, err)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{AocError, AocResult, Solution};
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = usize;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> AocResult<usize> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &usize) -> AocResult<usize> {
+            Ok(*lines)
+        }
+
+        fn part2(_: &usize) -> AocResult<usize> {
+            Err(AocError::no_solution("there is no part 2"))
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = ();
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(_: &str) -> AocResult<()> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> AocResult<usize> {
+            panic!("part 1 exploded")
+        }
+
+        fn part2(_: &()) -> AocResult<usize> {
+            Ok(2)
+        }
+    }
+
+    const LINES: Day = Day::new::<Lines>(1, "a\nb\nc\n");
+    const PANICS: Day = Day::new::<Panics>(2, "");
+
+    #[test]
+    fn runs_every_part_and_collects_errors() {
+        let (runs, _) = run_parallel(&[&PANICS, &LINES], &[1, 2], &RunOptions::default(), 3);
+        let outcomes: Vec<(u8, u8, Result<String, String>)> = runs
+            .iter()
+            .map(|run| {
+                let result = match &run.result {
+                    Ok(report) => Ok(report.parts[0].answer.clone()),
+                    Err(err) => Err(err.to_string()),
+                };
+                (run.day, run.part, result)
+            })
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (1, 1, Ok("3".to_string())),
+                (1, 2, Err("no solution: there is no part 2".to_string())),
+                (2, 1, Err("panicked: part 1 exploded".to_string())),
+                (2, 2, Ok("2".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn summarizes_runs() {
+        let (runs, _) = run_parallel(&[&LINES], &[1, 2], &RunOptions::default(), 1);
+        let mut out = vec![];
+        write_summary(&mut out, &runs, Duration::from_millis(5)).unwrap();
+        let summary = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = summary.lines().collect();
+
+        assert!(lines[1].starts_with("  1     1  3 "));
+        assert_eq!(lines[2], "  1     2  FAILED");
+        assert!(lines[4].starts_with("2 part(s), 1 failed, in 5ms"));
+        assert_eq!(lines[5], "Day 1 part 2: no solution: there is no part 2");
+        assert_eq!(answer_cell("\n#.#\n###\n"), "<2 lines>");
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::workspace_dir;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const BUILD_RS: &str = include_str!("../templates/build.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const TEST_ANSWERS: &str = include_str!("../templates/test.answers.toml.tmpl");
+
+#[derive(Debug, clap::Args)]
+pub struct NewArgs {
+    /// Day to create a crate for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+pub fn new(args: &NewArgs) -> Result<()> {
+    let root = workspace_dir();
+    for path in generate(&root, args.day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    println!();
+    println!(
+        "Fetch the input with `cargo run -p aoc -- fetch --day {}`",
+        args.day
+    );
+    Ok(())
+}
+
+/// Fills in the `{{N}}` (day number) and `{{NN}}` (zero padded day number) placeholders.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+}
+
+/// Creates the `dayNN` crate under `root` and registers it with the `aoc` runner, returning the
+/// files written.
+fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let crate_dir = root.join("crates").join(&name);
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+
+    // Work out the runner's new manifest before writing anything, so a runner that can't be
+    // updated fails without leaving a half made crate behind. Its build script lists the days
+    // from the manifest's dependencies.
+    let manifest_path = root.join("crates/aoc/Cargo.toml");
+    let manifest = insert_sorted(
+        &read(&manifest_path)?,
+        day,
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        |line| line.strip_prefix("day")?.split_once(" = ")?.0.parse().ok(),
+    )?;
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML, day)),
+        (crate_dir.join("build.rs"), render(BUILD_RS, day)),
+        (crate_dir.join("src/lib.rs"), render(LIB_RS, day)),
+        (crate_dir.join("src/main.rs"), render(MAIN_RS, day)),
+        (crate_dir.join("src/input/input.txt"), String::new()),
+        (crate_dir.join("src/input/test.txt"), String::new()),
+        (
+            crate_dir.join("src/input/test.answers.toml"),
+            render(TEST_ANSWERS, day),
+        ),
+        (manifest_path, manifest),
+    ];
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Inserts `line` among the lines `day_of` recognizes as registering a day, keeping them
+/// ordered by day.
+fn insert_sorted(
+    text: &str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if registered.iter().any(|&(_, registered)| registered == day) {
+        bail!("day {} is already registered", day);
+    }
+
+    let &(last, _) = registered
+        .last()
+        .ok_or_else(|| anyhow!("no registered days to add day {} to", day))?;
+    let at = registered
+        .iter()
+        .find(|&&(_, registered)| registered > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::temp_dir;
+    use crate::fetch::copy_input;
+
+    /// A temporary workspace with this runner's manifest to register days in.
+    fn workspace(name: &str) -> PathBuf {
+        let root = temp_dir(name);
+        fs::create_dir_all(root.join("crates/aoc")).unwrap();
+        fs::write(
+            root.join("crates/aoc/Cargo.toml"),
+            include_str!("../Cargo.toml"),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn generates_and_registers_day() {
+        let root = workspace("new");
+
+        generate(&root, 24).unwrap();
+        let lib = fs::read_to_string(root.join("crates/day24/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day24;"));
+        assert!(lib.contains("common::register!(24, Day24);"));
+        assert!(root.join("crates/day24/src/input/test.txt").exists());
+        assert!(root
+            .join("crates/day24/src/input/test.answers.toml")
+            .exists());
+        assert!(root.join("crates/day24/build.rs").exists());
+
+        let manifest = fs::read_to_string(root.join("crates/aoc/Cargo.toml")).unwrap();
+        assert!(manifest.contains("day24 = { path = \"../day24\" }\n"));
+
+        assert!(generate(&root, 24).is_err());
+    }
+
+    #[test]
+    fn fetched_input_replaces_the_empty_one() {
+        let root = workspace("new-fetch");
+        generate(&root, 24).unwrap();
+
+        let input_path = root.join("crates/day24/src/input/input.txt");
+        copy_input(&input_path, "1\n2\n").unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1\n2\n");
+        // Once there is a real input, a different download doesn't replace it.
+        copy_input(&input_path, "3\n").unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1\n2\n");
+    }
+}
@@ -0,0 +1,308 @@
+use anyhow::Result;
+use common::{Answers, Day};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::YEAR;
+
+/// How long the mock server makes clients wait after a wrong answer.
+const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// A local stand-in for the Advent of Code website, serving puzzle inputs and judging answers
+/// over plain HTTP so the client can be tested offline.
+///
+/// Like the real site, only requests carrying the expected session cookie are served, wrong
+/// answers start a cooldown for that day, and the responses use the site's wording.
+pub struct MockServer {
+    addr: SocketAddr,
+    #[cfg(test)]
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    session: String,
+    inputs: HashMap<(u16, u8), String>,
+    answers: HashMap<(u16, u8, u8), String>,
+    solved: HashSet<(u16, u8, u8)>,
+    cooldowns: HashMap<(u16, u8), Instant>,
+    requests: usize,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+impl MockServer {
+    /// Binds to `addr` (use port 0 for any free port) and serves requests on a background thread.
+    ///
+    /// `answers` are keyed by year, day and part.
+    pub fn start(
+        addr: &str,
+        session: &str,
+        inputs: HashMap<(u16, u8), String>,
+        answers: HashMap<(u16, u8, u8), String>,
+    ) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State {
+            session: session.to_string(),
+            inputs,
+            answers,
+            solved: HashSet::new(),
+            cooldowns: HashMap::new(),
+            requests: 0,
+        }));
+
+        let server_state = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                // A broken connection only affects that request.
+                let _ = handle(stream, &server_state);
+            }
+        });
+
+        Ok(Self {
+            addr,
+            #[cfg(test)]
+            state,
+        })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// How many requests have been answered so far.
+    #[cfg(test)]
+    pub fn requests(&self) -> usize {
+        self.state.lock().unwrap().requests
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Result<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body)?,
+    })
+}
+
+fn has_session(request: &Request, session: &str) -> bool {
+    request.headers.get("cookie").is_some_and(|cookie| {
+        cookie
+            .split(';')
+            .any(|c| c.trim() == format!("session={}", session))
+    })
+}
+
+/// Decodes an `application/x-www-form-urlencoded` body.
+fn parse_form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (decode(name), decode(value)))
+        .collect()
+}
+
+fn decode(s: &str) -> String {
+    let mut bytes = vec![];
+    let mut chars = s.bytes();
+    while let Some(b) = chars.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = chars.by_ref().take(2).collect();
+                let hex = String::from_utf8_lossy(&hex);
+                bytes.push(u8::from_str_radix(&hex, 16).unwrap_or(b'?'));
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Splits `/2021/day/7/input` into `(2021, 7, "input")`.
+fn parse_path(path: &str) -> Option<(u16, u8, &str)> {
+    let mut parts = path.trim_start_matches('/').split('/');
+    let year = parts.next()?.parse().ok()?;
+    if parts.next()? != "day" {
+        return None;
+    }
+    let day = parts.next()?.parse().ok()?;
+    let action = parts.next()?;
+    Some((year, day, action))
+}
+
+fn route(request: &Request, state: &mut State) -> (u16, String) {
+    match (request.method.as_str(), parse_path(&request.path)) {
+        ("GET", Some((year, day, "input"))) => {
+            if !has_session(request, &state.session) {
+                return (
+                    400,
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                        .to_string(),
+                );
+            }
+            match state.inputs.get(&(year, day)) {
+                Some(input) => (200, input.clone()),
+                None => (404, "404 Not Found\n".to_string()),
+            }
+        }
+        ("POST", Some((year, day, "answer"))) => {
+            if !has_session(request, &state.session) {
+                return (400, "Please log in to submit answers.\n".to_string());
+            }
+            let form = parse_form(&request.body);
+            let level = form.get("level").and_then(|level| level.parse().ok());
+            match (level, form.get("answer")) {
+                (Some(part), Some(answer)) => (
+                    200,
+                    format!(
+                        "<main>\n<article><p>{}</p></article>\n</main>\n",
+                        judge(state, year, day, part, answer)
+                    ),
+                ),
+                _ => (400, "Bad Request\n".to_string()),
+            }
+        }
+        _ => (404, "404 Not Found\n".to_string()),
+    }
+}
+
+/// Judges an answer the way the real site does, returning its message.
+fn judge(state: &mut State, year: u16, day: u8, part: u8, answer: &str) -> String {
+    let now = Instant::now();
+    if let Some(left) = state
+        .cooldowns
+        .get(&(year, day))
+        .and_then(|until| until.checked_duration_since(now))
+    {
+        return format!(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have {}s left to wait.",
+            left.as_secs().max(1)
+        );
+    }
+
+    let expected = state.answers.get(&(year, day, part));
+    let unlocked = part == 1 || state.solved.contains(&(year, day, 1));
+    let expected = match expected {
+        Some(expected) if unlocked && !state.solved.contains(&(year, day, part)) => expected,
+        _ => {
+            return "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_string()
+        }
+    };
+
+    if answer == expected {
+        state.solved.insert((year, day, part));
+        return "That's the right answer!  You are one gold star closer to saving Christmas."
+            .to_string();
+    }
+
+    state
+        .cooldowns
+        .insert((year, day), now + WRONG_ANSWER_COOLDOWN);
+    let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+    format!(
+        "That's not the right answer{}.  If you're stuck, make sure you're using the full input \
+         data.  Please wait one minute before trying again.",
+        hint
+    )
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let request = read_request(&mut reader)?;
+
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        state.requests += 1;
+        route(&request, &mut state)
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[derive(Debug, clap::Args)]
+pub struct MockServerArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8021")]
+    addr: String,
+
+    /// Session cookie that requests have to carry
+    #[arg(long, default_value = "mock-session")]
+    session: String,
+}
+
+/// Serves the embedded inputs of `days`, judging answers against `answers`, until the process is
+/// killed.
+pub fn serve(args: &MockServerArgs, days: &[Day], answers: &Answers) -> Result<()> {
+    let inputs = days
+        .iter()
+        .map(|day| ((YEAR, day.number), day.input.to_string()))
+        .collect();
+    let answers = days
+        .iter()
+        .flat_map(|day| [(day.number, 1), (day.number, 2)])
+        .filter_map(|(day, part)| {
+            let answer = answers.get(day, part)?;
+            Some(((YEAR, day, part), answer.to_string()))
+        })
+        .collect();
+    let server = MockServer::start(&args.addr, &args.session, inputs, answers)?;
+    println!("Serving {} inputs at {}", days.len(), server.url());
+    loop {
+        thread::park();
+    }
+}
@@ -0,0 +1,487 @@
+use anyhow::{anyhow, bail, Context, Result};
+use common::{run_day, RunOptions};
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::Client;
+use crate::fetch::SiteArgs;
+use crate::{find_day, YEAR};
+
+/// How long the site usually asks to wait after a wrong answer that doesn't say.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Not judged because the previous answer was submitted too recently.
+    TooRecent,
+    /// Not judged because the part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] = [
+        Verdict::Correct,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wrong,
+        Verdict::TooRecent,
+        Verdict::WrongLevel,
+    ];
+
+    /// Name of the verdict in the submission log.
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too-recent",
+            Verdict::WrongLevel => "wrong-level",
+        }
+    }
+
+    /// Whether the answer was judged and is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Verdict::ALL
+            .into_iter()
+            .find(|verdict| verdict.name() == s)
+            .ok_or_else(|| anyhow!("unknown verdict: {}", s))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "is the right answer",
+            Verdict::TooHigh => "is too high",
+            Verdict::TooLow => "is too low",
+            Verdict::Wrong => "is not the right answer",
+            Verdict::TooRecent => "was not judged, the last answer was submitted too recently",
+            Verdict::WrongLevel => "was not judged, is the part already solved?",
+        })
+    }
+}
+
+/// A verdict and how long the site asks to wait before submitting the next answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub wait: Duration,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the site responds to a submission with.
+    pub fn parse(page: &str) -> Result<Self> {
+        let message = article_text(page);
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            bail!("unrecognized response: {}", message.trim());
+        };
+
+        let wait = match verdict {
+            Verdict::TooRecent => parse_time_left(&message)
+                .ok_or_else(|| anyhow!("missing time left in response: {}", message.trim()))?,
+            verdict if verdict.is_wrong() => {
+                parse_please_wait(&message).unwrap_or(DEFAULT_COOLDOWN)
+            }
+            _ => Duration::ZERO,
+        };
+        Ok(Self { verdict, wait })
+    }
+}
+
+/// The text of the page's `<article>`, which holds the message, with the markup removed.
+fn article_text(page: &str) -> String {
+    let start = page.find("<article>").unwrap_or(0);
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Parses `You have 4m 32s left to wait.`
+fn parse_time_left(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+    message[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(Duration::from_secs(value * seconds))
+        })
+        .sum()
+}
+
+/// Parses `Please wait one minute before trying again.`
+fn parse_please_wait(message: &str) -> Option<Duration> {
+    let start = message.to_ascii_lowercase().find("please wait ")? + "please wait ".len();
+    let mut words = message[start..].split_whitespace();
+    let value: u64 = match words.next()? {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+    let unit = words.next()?;
+    let seconds = if unit.starts_with("second") {
+        1
+    } else if unit.starts_with("minute") {
+        60
+    } else if unit.starts_with("hour") {
+        3600
+    } else {
+        return None;
+    };
+    Some(Duration::from_secs(value * seconds))
+}
+
+/// One submitted answer, as recorded in the submission log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    /// When the next answer for this day may be submitted, in seconds since the Unix epoch.
+    fn cooldown_until(&self) -> u64 {
+        self.time + self.outcome.wait.as_secs()
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [time, day, part, answer, verdict, wait] = fields[..] else {
+            bail!("expected 6 tab separated fields");
+        };
+        Ok(Self {
+            time: time.parse()?,
+            day: day.parse()?,
+            part: part.parse()?,
+            answer: answer.to_string(),
+            outcome: Outcome {
+                verdict: verdict.parse()?,
+                wait: Duration::from_secs(wait.parse()?),
+            },
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.day,
+            self.part,
+            self.answer,
+            self.outcome.verdict.name(),
+            self.outcome.wait.as_secs()
+        )
+    }
+}
+
+/// Every answer submitted for one year, one tab separated line per attempt:
+/// `time day part answer verdict wait`.
+pub struct SubmissionLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Loads the log at `path`, which doesn't have to exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(log) => log
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .with_context(|| format!("invalid line {} in {}", i + 1, path.display()))
+                })
+                .collect::<Result<_>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// Refuses `answer` if submitting it would be pointless or too early: the part is already
+    /// solved, the answer was already judged wrong or is out of the known bounds, or the day is
+    /// still cooling down at `now`.
+    ///
+    /// Returns the earlier attempt if exactly this answer was already accepted.
+    fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<Option<&Attempt>> {
+        let value = answer.parse::<i64>().ok();
+        for attempt in self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+        {
+            let verdict = attempt.outcome.verdict;
+            if verdict == Verdict::Correct {
+                if attempt.answer == answer {
+                    return Ok(Some(attempt));
+                }
+                bail!("part {} is already solved, with {}", part, attempt.answer);
+            }
+            if verdict.is_wrong() && attempt.answer == answer {
+                bail!("{} was already submitted and {}", answer, verdict);
+            }
+            if let (Some(value), Ok(submitted)) = (value, attempt.answer.parse::<i64>()) {
+                if (verdict == Verdict::TooHigh && value >= submitted)
+                    || (verdict == Verdict::TooLow && value <= submitted)
+                {
+                    bail!("{} can't be right, {} {}", answer, submitted, verdict);
+                }
+            }
+        }
+
+        let cooldown_until = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day)
+            .map(Attempt::cooldown_until)
+            .max();
+        if let Some(until) = cooldown_until.filter(|&until| until > now) {
+            bail!(
+                "wait {}s before submitting another answer for day {}",
+                until - now,
+                day
+            );
+        }
+        Ok(None)
+    }
+
+    /// Appends `attempt` to the log file.
+    fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", attempt)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Submits `answer` for `year`/`day`/`part` and logs the attempt, unless the log already
+/// shows that the answer can't be right or that the day is still cooling down.
+///
+/// An answer that was already accepted isn't submitted again: its logged outcome is returned.
+pub fn submit_answer(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        bail!("answers are a single word, got {:?}", answer);
+    }
+
+    let mut log = SubmissionLog::load(&client.submissions_path(year))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    if let Some(attempt) = log.check(day, part, answer, now)? {
+        return Ok(attempt.outcome);
+    }
+
+    let page = client.post_answer(year, day, part, answer)?;
+    let outcome = Outcome::parse(&page)?;
+    log.record(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+    })?;
+    Ok(outcome)
+}
+
+#[derive(Debug, clap::Args)]
+pub struct SubmitArgs {
+    /// Day to submit an answer for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to submit an answer for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Puzzle year
+    #[arg(short, long, default_value_t = YEAR)]
+    year: u16,
+
+    /// Answer to submit [default: the answer the day's solution computes for its input]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+pub fn submit(args: &SubmitArgs) -> Result<()> {
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None if args.year == YEAR => {
+            let report = run_day(find_day(args.day)?, &[args.part], &RunOptions::default())?;
+            report.parts[0].answer.clone()
+        }
+        None => bail!("an answer is required for years other than {}", YEAR),
+    };
+
+    let outcome = submit_answer(&args.site.client(), args.year, args.day, args.part, &answer)?;
+    let message = format!(
+        "Day {} part {}: {} {}",
+        args.day, args.part, answer, outcome.verdict
+    );
+    if outcome.verdict == Verdict::Correct {
+        println!("{}", message);
+        return Ok(());
+    }
+    if outcome.wait > Duration::ZERO {
+        bail!("{}, wait {:?} before trying again", message, outcome.wait);
+    }
+    bail!("{}", message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::temp_dir;
+    use crate::server::MockServer;
+    use std::collections::HashMap;
+
+    #[test]
+    fn parses_responses() {
+        let outcome = |message: &str| {
+            Outcome::parse(&format!(
+                "<main>\n<article><p>{}</p></article>\n</main>",
+                message
+            ))
+            .unwrap()
+        };
+
+        assert_eq!(
+            outcome("That's the right answer!  You are <em>one gold star</em> closer."),
+            Outcome {
+                verdict: Verdict::Correct,
+                wait: Duration::ZERO
+            }
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+            Outcome {
+                verdict: Verdict::TooLow,
+                wait: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(
+            outcome("That's not the right answer.  please wait 5 minutes before trying again."),
+            Outcome {
+                verdict: Verdict::Wrong,
+                wait: Duration::from_secs(300)
+            }
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait."),
+            Outcome {
+                verdict: Verdict::TooRecent,
+                wait: Duration::from_secs(272)
+            }
+        );
+        assert!(Outcome::parse("<article><p>Something else</p></article>").is_err());
+    }
+
+    #[test]
+    fn never_resubmits_wrong_answers() {
+        let mut answers = HashMap::new();
+        answers.insert((2021, 1, 1), "7".to_string());
+        answers.insert((2021, 2, 1), "150".to_string());
+        let server = MockServer::start("127.0.0.1:0", "secret", HashMap::new(), answers).unwrap();
+        let client = Client::new(&server.url(), "secret", &temp_dir("submit"))
+            .with_min_interval(Duration::ZERO);
+
+        let outcome = submit_answer(&client, 2021, 1, 1, "9").unwrap();
+        assert_eq!(outcome.verdict, Verdict::TooHigh);
+        assert_eq!(outcome.wait, Duration::from_secs(60));
+        // Known wrong, out of bounds, and cooling down: none of these reach the server.
+        assert!(submit_answer(&client, 2021, 1, 1, "9").is_err());
+        assert!(submit_answer(&client, 2021, 1, 1, "12").is_err());
+        assert!(submit_answer(&client, 2021, 1, 1, "7").is_err());
+        assert_eq!(server.requests(), 1);
+
+        let outcome = submit_answer(&client, 2021, 2, 1, "150").unwrap();
+        assert_eq!(outcome.verdict, Verdict::Correct);
+        assert_eq!(submit_answer(&client, 2021, 2, 1, "150").unwrap(), outcome);
+        assert!(submit_answer(&client, 2021, 2, 1, "151").is_err());
+        assert_eq!(server.requests(), 2);
+
+        let log = SubmissionLog::load(&client.submissions_path(2021)).unwrap();
+        assert_eq!(log.attempts.len(), 2);
+        assert_eq!(log.attempts[0].answer, "9");
+    }
+}
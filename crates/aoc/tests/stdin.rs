@@ -0,0 +1,26 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn visualizes_input_read_from_stdin() {
+    let mut aoc = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--day", "11", "--input", "-", "--viz", "text"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    aoc.stdin
+        .take()
+        .unwrap()
+        .write_all(include_bytes!("../../day11/src/input/test.txt"))
+        .unwrap();
+    let output = aoc.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Part 1: 1656\n"));
+    assert!(stdout.contains("Part 2: 195\n"));
+    // The frames show the same octopuses the answers came from.
+    assert!(stdout.contains("Step 0\n5483143223\n"));
+    assert!(stdout.contains("Step 195: 100 flashes\n"));
+}
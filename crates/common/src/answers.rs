@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Known answers for the real puzzle inputs, keyed by day and part.
+///
+/// Answers are stored as TOML with a table per day, e.g. `[day01]` with `part1 = 1387`. Values may
+/// be integers or strings and are compared against the string form of a solver's answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+pub(crate) fn parse_key(key: &str, prefix: &str) -> Result<u8> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| anyhow!("invalid key `{}`, expected `{}N`", key, prefix))
+}
+
+/// The string form of an answer, which may be written as a string or an integer.
+pub(crate) fn answer_value(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(answer) => Some(answer.clone()),
+        toml::Value::Integer(answer) => Some(answer.to_string()),
+        _ => None,
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let table: toml::Table = s.parse()?;
+        let mut answers = HashMap::new();
+
+        for (day_key, parts) in table {
+            let day = parse_key(&day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| anyhow!("`{}` must be a table", day_key))?;
+            for (part_key, answer) in parts {
+                let part = parse_key(part_key, "part")?;
+                let answer = answer_value(answer).ok_or_else(|| {
+                    anyhow!("`{}.{}` must be a string or an integer", day_key, part_key)
+                })?;
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers from {}", path.display()))?
+            .parse()
+            .with_context(|| format!("invalid answers file {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+}
+
+/// Describes how `actual` differs from `expected`, line by line, with `-` marking expected
+/// lines and `+` marking actual ones.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.split('\n').collect();
+    let actual: Vec<&str> = actual.split('\n').collect();
+    let mut output = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => output.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    output.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    output.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers() {
+        let answers: Answers = "[day01]\npart1 = 7\n\n[day13]\npart2 = \"\"\"\n#.\n.#\"\"\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(1, 1), Some("7"));
+        assert_eq!(answers.get(1, 2), None);
+        assert_eq!(answers.get(13, 2), Some("#.\n.#"));
+        assert!("[day1x]\npart1 = 7\n".parse::<Answers>().is_err());
+        assert!("[day01]\npart1 = 1.5\n".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(diff("5212", "5213"), "- 5212\n+ 5213\n");
+        assert_eq!(diff("#.\n.#", "#.\n##\n"), "  #.\n- .#\n+ ##\n+ \n");
+    }
+}
@@ -0,0 +1,274 @@
+use anyhow::{anyhow, Result};
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
+
+use crate::memory::{self, AllocStats};
+use crate::spans::{self, SpanTiming};
+
+const DEFAULT_WARMUP: u32 = 3;
+const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Options controlling how each step of a solution is timed.
+#[derive(Debug, clap::Args)]
+pub struct BenchOptions {
+    /// Benchmark each step over many runs instead of timing a single run
+    #[arg(short, long)]
+    pub bench: bool,
+
+    /// Untimed runs of each step before benchmarking it
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_WARMUP, requires = "bench")]
+    pub warmup: u32,
+
+    /// Benchmark each step exactly N times
+    #[arg(long, value_name = "N", requires = "bench", conflicts_with = "budget")]
+    pub iterations: Option<u32>,
+
+    /// Benchmark each step for this long, e.g. `500ms` or `2s` [default: 1s]
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration, requires = "bench")]
+    pub budget: Option<Duration>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            bench: false,
+            warmup: DEFAULT_WARMUP,
+            iterations: None,
+            budget: None,
+        }
+    }
+}
+
+/// Parses a duration such as `750ms`, `2s` or `100us`.
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let unit_start = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| anyhow!("missing unit in duration: {}", s))?;
+    let (value, unit) = s.split_at(unit_start);
+    let value: f64 = value.parse()?;
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        _ => return Err(anyhow!("unknown unit in duration: {}", s)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|err| anyhow!("invalid duration {}: {}", s, err))
+}
+
+/// Summary statistics over the timed runs of a benchmarked step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>().as_secs_f64() / runs as f64;
+        let variance = if runs > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// How long a step took: a single run, or statistics over a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing {
+    Single(Duration),
+    Bench(Stats),
+}
+
+impl Timing {
+    /// Statistics for this timing, treating a single run as a benchmark of one run.
+    pub fn stats(&self) -> Stats {
+        match *self {
+            Timing::Single(elapsed) => Stats {
+                runs: 1,
+                min: elapsed,
+                mean: elapsed,
+                median: elapsed,
+                stddev: Duration::ZERO,
+            },
+            Timing::Bench(stats) => stats,
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Timing::Single(elapsed) => write!(f, "(elapsed: {:?})", elapsed),
+            Timing::Bench(stats) => write!(
+                f,
+                "(min: {:?}, mean: {:?}, median: {:?}, stddev: {:?}, runs: {})",
+                stats.min, stats.mean, stats.median, stats.stddev, stats.runs
+            ),
+        }
+    }
+}
+
+/// The timing of a step, along with its heap usage when allocations are counted and the time
+/// spent in each of its spans when those are recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub timing: Timing,
+    /// Heap usage of the step's last run, if built with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Spans of the step's last run, if built with the `spans` feature.
+    pub spans: Vec<SpanTiming>,
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.timing)?;
+        if let Some(alloc) = self.alloc {
+            write!(f, " {}", alloc)?;
+        }
+        for span in &self.spans {
+            write!(
+                f,
+                "\n{:indent$}{}: {:?}",
+                "",
+                span.name,
+                span.elapsed,
+                indent = 2 * (span.depth + 1)
+            )?;
+            if span.calls > 1 {
+                write!(f, " ({} calls)", span.calls)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What one run of a step returned and how it went.
+struct Run<T> {
+    value: Result<T>,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+    spans: Vec<SpanTiming>,
+}
+
+/// Runs `step` once, timing it, counting its allocations and collecting its spans.
+fn run_once<T>(step: &mut impl FnMut() -> Result<T>) -> Run<T> {
+    let (((value, elapsed), alloc), spans) = spans::collect(|| {
+        memory::track(|| {
+            let now = Instant::now();
+            let value = step();
+            (value, now.elapsed())
+        })
+    });
+    Run {
+        value,
+        elapsed,
+        alloc,
+        spans,
+    }
+}
+
+impl BenchOptions {
+    /// Times `step`, returning the value of its last run.
+    ///
+    /// Only the call to `step` is timed: dropping the previous run's value and anything the
+    /// caller does with the result happen outside of the measurement.
+    pub fn measure<T>(&self, mut step: impl FnMut() -> Result<T>) -> Result<(T, Measurement)> {
+        if !self.bench {
+            let run = run_once(&mut step);
+            let measurement = Measurement {
+                timing: Timing::Single(run.elapsed),
+                alloc: run.alloc,
+                spans: run.spans,
+            };
+            return Ok((run.value?, measurement));
+        }
+
+        for _ in 0..self.warmup {
+            step()?;
+        }
+
+        let budget = self.budget.unwrap_or(DEFAULT_BUDGET);
+        let started = Instant::now();
+        let mut samples = vec![];
+        loop {
+            let run = run_once(&mut step);
+            samples.push(run.elapsed);
+            let value = run.value?;
+
+            let done = match self.iterations {
+                Some(iterations) => samples.len() >= iterations as usize,
+                None => started.elapsed() >= budget,
+            };
+            if done {
+                let stats =
+                    Stats::from_samples(&samples).ok_or_else(|| anyhow!("no timed runs"))?;
+                let measurement = Measurement {
+                    timing: Timing::Bench(stats),
+                    alloc: run.alloc,
+                    spans: run.spans,
+                };
+                return Ok((value, measurement));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("2s").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("750ms").unwrap(), Duration::from_millis(750));
+        assert_eq!(parse_duration("1.5us").unwrap(), Duration::from_nanos(1500));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("99999999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn computes_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1290);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::parse::ParseError;
+
+/// Why a [`Solution`](crate::Solution) couldn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input doesn't have the format the puzzle describes.
+    MalformedInput(ParseError),
+    /// The input is well formed, but the puzzle has no answer for it, such as bingo boards none
+    /// of which ever wins.
+    NoSolution(String),
+    /// An intermediate value got too big for the integer type holding it.
+    Overflow(String),
+}
+
+/// The result of parsing or solving a day's puzzle.
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn no_solution(message: impl Display) -> Self {
+        Self::NoSolution(message.to_string())
+    }
+
+    pub fn overflow(message: impl Display) -> Self {
+        Self::Overflow(message.to_string())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MalformedInput(err) => write!(f, "malformed input: {}", err),
+            AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+            AocError::Overflow(message) => write!(f, "arithmetic overflow: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::MalformedInput(err) => Some(err),
+            AocError::NoSolution(_) | AocError::Overflow(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(err: ParseError) -> Self {
+        AocError::MalformedInput(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Span;
+
+    #[test]
+    fn wraps_parse_errors() {
+        let err: AocError = Span::new("1\nx\n")
+            .lines()
+            .map(Span::parse::<u8>)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err()
+            .into();
+        assert!(matches!(&err, AocError::MalformedInput(err) if err.line == 2));
+        assert_eq!(
+            err.to_string(),
+            "malformed input: line 2, column 1: invalid digit found in string (at \"x\")"
+        );
+    }
+
+    #[test]
+    fn survives_conversion_to_anyhow() {
+        let err = anyhow::Error::from(AocError::overflow("fuel doesn't fit in a u32"));
+        assert_eq!(
+            err.downcast_ref::<AocError>(),
+            Some(&AocError::Overflow("fuel doesn't fit in a u32".to_string()))
+        );
+        assert_eq!(
+            err.to_string(),
+            "arithmetic overflow: fuel doesn't fit in a u32"
+        );
+    }
+}
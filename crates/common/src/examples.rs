@@ -0,0 +1,221 @@
+//! Tests generated from the puzzle examples in a day's `src/input` directory.
+//!
+//! Every `NAME.txt` there with a `NAME.answers.toml` next to it is an example, and each part the
+//! sidecar lists, e.g. `part1 = 226` or a multi-line string for part 2, becomes its own test. A
+//! day's build script calls [`generate_tests`] and its test module pulls the tests in with
+//! [`example_tests!`](crate::example_tests!), so adding an example takes two files and no Rust.
+
+use anyhow::{anyhow, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{answer_value, diff, parse_key};
+use crate::{normalize_input, Day};
+
+/// The file name suffix that marks a file as the expected answers of the example next to it.
+const SIDECAR_SUFFIX: &str = ".answers.toml";
+
+/// A puzzle example with the answers it should give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The input's file name without `.txt`.
+    pub name: String,
+    pub input: PathBuf,
+    /// The expected answer of each part the sidecar lists, by part.
+    pub answers: Vec<(u8, String)>,
+}
+
+/// Finds the examples in `dir`, ordered by name. Inputs without a sidecar aren't examples.
+pub fn find(dir: &Path) -> Result<Vec<Example>> {
+    let mut examples = vec![];
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let sidecar = entry?.path();
+        let Some(name) = sidecar
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(SIDECAR_SUFFIX))
+        else {
+            continue;
+        };
+        let input = dir.join(format!("{}.txt", name));
+        if !input.is_file() {
+            return Err(anyhow!(
+                "{} has no example input {}",
+                sidecar.display(),
+                input.display()
+            ));
+        }
+        let answers = fs::read_to_string(&sidecar)
+            .with_context(|| format!("failed to read {}", sidecar.display()))?;
+        examples.push(Example {
+            name: name.to_string(),
+            input,
+            answers: parse_answers(&answers)
+                .with_context(|| format!("invalid answers file {}", sidecar.display()))?,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Parses a sidecar: `part1`/`part2` keys with a string or integer answer each.
+fn parse_answers(s: &str) -> Result<Vec<(u8, String)>> {
+    let table: toml::Table = s.parse()?;
+    let mut answers = vec![];
+    for (key, answer) in &table {
+        let part = parse_key(key, "part")?;
+        let answer = answer_value(answer)
+            .ok_or_else(|| anyhow!("`{}` must be a string or an integer", key))?;
+        answers.push((part, answer));
+    }
+    answers.sort();
+    Ok(answers)
+}
+
+/// Renders a `#[test]` function for every part of every example, named after the example and the
+/// part, e.g. `example_test1_part2`.
+pub fn render(examples: &[Example]) -> String {
+    let mut tests = String::new();
+    for example in examples {
+        let name: String = example
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        for (part, answer) in &example.answers {
+            tests.push_str(&format!(
+                "#[test]\n\
+                 fn example_{}_part{}() {{\n    \
+                     ::common::examples::check(&crate::DAY, include_str!({:?}), {}, {:?});\n\
+                 }}\n\n",
+                name,
+                part,
+                example.input.display().to_string(),
+                part,
+                answer
+            ));
+        }
+    }
+    tests
+}
+
+/// Writes the tests for the examples in the calling crate's `src/input` directory to
+/// `$OUT_DIR/example_tests.rs`. Called from a day's build script.
+pub fn generate_tests() -> Result<()> {
+    let manifest_dir =
+        env::var_os("CARGO_MANIFEST_DIR").context("CARGO_MANIFEST_DIR is not set")?;
+    let out_dir = env::var_os("OUT_DIR").context("OUT_DIR is not set")?;
+    let dir = Path::new(&manifest_dir).join("src").join("input");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let examples = find(&dir)?;
+    // The inputs themselves are tracked by the `include_str!`s in the tests.
+    for example in &examples {
+        let sidecar = example.input.with_extension("answers.toml");
+        println!("cargo:rerun-if-changed={}", sidecar.display());
+    }
+    let path = Path::new(&out_dir).join("example_tests.rs");
+    fs::write(&path, render(&examples))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Solves `part` of `day` for `input`, normalized like any other input, panicking with a line diff
+/// unless the answer is `expected`.
+pub fn check(day: &Day, input: &str, part: u8, expected: &str) {
+    let parsed = day.parse(&normalize_input(input)).unwrap();
+    let actual = day.solve(part, parsed.as_ref()).unwrap();
+    if actual != expected {
+        panic!(
+            "day {} part {} gave the wrong answer:\n{}",
+            day.number,
+            part,
+            diff(expected, &actual)
+        );
+    }
+}
+
+/// Includes the tests [`generate_tests`] wrote for this crate's examples. Goes in the test module
+/// of a day crate whose build script calls it.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocResult, Solution};
+
+    #[test]
+    fn finds_examples_with_sidecars() {
+        let dir = env::temp_dir().join(format!("common-examples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "1\n").unwrap();
+        fs::write(dir.join("test-2.txt"), "2\n").unwrap();
+        fs::write(
+            dir.join("test-2.answers.toml"),
+            "part2 = \"\"\"\n#.\n.#\"\"\"\npart1 = 7\n",
+        )
+        .unwrap();
+
+        let examples = find(&dir).unwrap();
+        assert_eq!(
+            examples,
+            vec![Example {
+                name: "test-2".to_string(),
+                input: dir.join("test-2.txt"),
+                answers: vec![(1, "7".to_string()), (2, "#.\n.#".to_string())],
+            }]
+        );
+        let tests = render(&examples);
+        assert!(tests.contains("fn example_test_2_part1() {\n"));
+        assert!(tests.contains(", 2, \"#.\\n.#\");\n"));
+
+        fs::write(dir.join("missing.answers.toml"), "part1 = 1\n").unwrap();
+        assert!(find(&dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> AocResult<Vec<u32>> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part1(numbers: &Vec<u32>) -> AocResult<u32> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part2(numbers: &Vec<u32>) -> AocResult<usize> {
+            Ok(numbers.len())
+        }
+    }
+
+    #[test]
+    fn checks_answers() {
+        let day = Day::new::<Sum>(1, "");
+        check(&day, "1\n2\n", 1, "3");
+        check(&day, "1\n2\n", 2, "2");
+        let wrong = std::panic::catch_unwind(|| check(&day, "1\n2\n", 1, "4"));
+        let message = wrong.unwrap_err();
+        assert_eq!(
+            message.downcast_ref::<String>().unwrap(),
+            "day 1 part 1 gave the wrong answer:\n- 4\n+ 3\n"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Random puzzle inputs of any size, for stress testing solvers and feeding benchmarks.
+//!
+//! A [`Solution`](crate::Solution) that can make up its own input implements `generate`, which
+//! gets a size, whose meaning depends on the day (readings, boards, the side of a grid...), and a
+//! [`Rng`] to draw from. The same size and seed always give the same input, on every machine and
+//! with every version of this crate, so a generated input never has to be committed.
+
+/// A small, fast pseudo-random number generator (SplitMix64). It is not suitable for anything
+/// but making up inputs, but its output for a seed never changes.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick a number below 0");
+        // Scales the random bits down instead of taking a remainder, which is close enough to
+        // uniform for any `n` an input needs.
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// A number from `low` to `high`, both included.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Puts `items` in a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_sequences_for_a_seed() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        // Generated inputs depend on this exact sequence.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(2021);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let n = rng.between(3, 8);
+            assert!((3..=8).contains(&n));
+            seen[n - 3] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert!((0..100).all(|_| !rng.chance(0.0) && rng.chance(1.0)));
+
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::error::AocResult;
+use crate::parse::{ParseError, Span};
+use crate::point::Point;
+
+const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row in one contiguous `Vec` and indexed by `(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order. If they don't fill it exactly, the error points
+    /// at the row and column of the first missing or extra cell.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> AocResult<Self> {
+        if cells.len() != width * height {
+            let first_wrong = cells.len().min(width * height);
+            let (line, column) = match width {
+                0 => (1, 1),
+                _ => (first_wrong / width + 1, first_wrong % width + 1),
+            };
+            return Err(ParseError {
+                line,
+                column,
+                text: String::new(),
+                message: format!(
+                    "{} cells don't fill a {}x{} grid",
+                    cells.len(),
+                    width,
+                    height
+                ),
+            }
+            .into());
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid by calling `cell` with the position of every cell.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, turning every character into a cell with `cell`.
+    ///
+    /// See [`Span::grid`] for parsing a grid out of a larger input.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        Span::new(input).trim().grid(cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cell at `point`, or `None` outside of the grid.
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        )
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Positions of the up to 4 orthogonally adjacent cells of `(x, y)` inside the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        offset_positions(self.width, self.height, x, y, &NEIGHBORS4)
+    }
+
+    /// Positions of the up to 8 orthogonally or diagonally adjacent cells of `(x, y)` inside the
+    /// grid.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        offset_positions(self.width, self.height, x, y, &NEIGHBORS8)
+    }
+
+    /// Every position in the grid, in row order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in row order.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Parses a grid of single digits, such as a height or risk map.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Span::new(input).trim().digit_grid()
+    }
+}
+
+fn offset_positions(
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    offsets: &'static [(isize, isize)],
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < width && y < height).then_some((x, y))
+    })
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the {}x{} grid", x, y, width, height))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point).unwrap_or_else(|| {
+            panic!(
+                "{} is outside of the {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AocError;
+
+    #[test]
+    fn parses_and_displays_digits() {
+        let grid: Grid<u32> = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = Grid::<u32>::parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a digit (at \"x\")"
+        );
+        assert!(Grid::<u32>::parse_digits("123\n45\n").is_err());
+
+        assert!(Grid::new(2, 2, vec![1, 2, 3, 4]).is_ok());
+        let err = Grid::new(2, 2, vec![1, 2, 3]).unwrap_err();
+        assert!(matches!(err, AocError::MalformedInput(err) if (err.line, err.column) == (2, 2)));
+    }
+
+    #[test]
+    fn iterates_neighbors_rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |x, y| x + 10 * y);
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4(1, 1).count(), 3);
+        assert_eq!(grid.neighbors8(1, 0).count(), 5);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[0, 1, 2][..], &[10, 11, 12][..]]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect())
+                .collect::<Vec<Vec<_>>>(),
+            vec![vec![0, 10], vec![1, 11], vec![2, 12]]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use std::convert::Infallible;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where to read puzzle input from when it is not the input embedded in the binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        match s {
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::File(path.into())),
+        }
+    }
+}
+
+impl InputSource {
+    /// Reads the input as is; [`normalize_input`] cleans it up for parsing.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("failed to read input from stdin")?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("failed to read input from {}", path.display())),
+        }
+    }
+}
+
+/// Cleans up puzzle input however it was saved or pasted, so parsers only ever see `\n` line
+/// endings: drops a UTF-8 byte order mark, turns `\r\n` into `\n`, trims spaces and tabs from
+/// the end of every line and ends the input with exactly one newline. Empty input stays empty.
+pub fn normalize_input(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalized.push_str(line.trim_end_matches([' ', '\t', '\r']));
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_input_source() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "input/test.txt".parse::<InputSource>().unwrap(),
+            InputSource::File("input/test.txt".into())
+        );
+    }
+
+    #[test]
+    fn normalizes_input() {
+        assert_eq!(
+            normalize_input("\u{feff}6,10 \r\n0,14\t\r\n\r\nfold along y=7"),
+            "6,10\n0,14\n\nfold along y=7\n"
+        );
+        assert_eq!(normalize_input("1\n2\n\n\n"), "1\n2\n");
+        assert_eq!(normalize_input("  indented\n"), "  indented\n");
+        assert_eq!(normalize_input(""), "");
+    }
+}
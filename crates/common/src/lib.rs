@@ -1,12 +1,174 @@
+use anyhow::{anyhow, Result};
+use std::any::{type_name, Any};
+use std::fmt::Display;
+
+mod answers;
+mod bench;
+mod error;
+pub mod examples;
+pub mod generate;
+mod grid;
+mod input;
+mod memory;
+mod options;
+pub mod parse;
+mod point;
+mod report;
+pub mod search;
+pub mod spans;
+pub mod viz;
+
+pub use answers::{diff, Answers};
+pub use bench::{parse_duration, BenchOptions, Measurement, Stats, Timing};
+pub use error::{AocError, AocResult};
+pub use grid::Grid;
+pub use input::{normalize_input, InputSource};
+pub use memory::{AllocStats, CountingAlloc};
+pub use options::RunOptions;
+pub use point::{Point, Vec2};
+pub use report::{
+    run_day, run_day_on, write_csv, write_json, write_text, DayReport, Format, PartReport,
+};
+pub use spans::SpanTiming;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+/// A day's puzzle, split into a parse step that runs once and the two parts that share its
+/// output.
+///
+/// Every step fails with an [`AocError`] rather than panicking, so callers can tell bad input
+/// from a puzzle without an answer.
+pub trait Solution {
+    type Input: 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+    fn part1(input: &Self::Input) -> AocResult<Self::Part1>;
+    fn part2(input: &Self::Input) -> AocResult<Self::Part2>;
+
+    /// Records how the puzzle's simulation evolves, for days that have one to show.
+    fn visualize(_input: &Self::Input) -> AocResult<Option<viz::Recording>> {
+        Ok(None)
+    }
+
+    /// Makes up a valid input that grows with `size`, for days that can generate their own. See
+    /// [`generate`] for what it is for.
+    fn generate(_size: usize, _rng: &mut generate::Rng) -> Option<String> {
+        None
+    }
+}
+
+/// A day's [`Solution`] as registered with the `aoc` runner through [`register!`].
+///
+/// The parsed input and the answers are type-erased so that days with different input and answer
+/// types can live in the same list. Errors from the [`Solution`] keep their [`AocError`], which
+/// can be recovered with `downcast_ref`.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> Result<String>,
+    part2: fn(&dyn Any) -> Result<String>,
+    visualize: fn(&dyn Any) -> Result<Option<viz::Recording>>,
+    generate: fn(usize, &mut generate::Rng) -> Option<String>,
+    part1_type: fn() -> &'static str,
+    part2_type: fn() -> &'static str,
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Box<dyn Any>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any) -> Result<String> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("parsed input has the wrong type"))?;
+    Ok(S::part1(input)?.to_string())
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any) -> Result<String> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("parsed input has the wrong type"))?;
+    Ok(S::part2(input)?.to_string())
+}
+
+fn visualize_erased<S: Solution>(input: &dyn Any) -> Result<Option<viz::Recording>> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("parsed input has the wrong type"))?;
+    Ok(S::visualize(input)?)
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8, input: &'static str) -> Self {
+        Self {
+            number,
+            input,
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: part2_erased::<S>,
+            visualize: visualize_erased::<S>,
+            generate: S::generate,
+            part1_type: type_name::<S::Part1>,
+            part2_type: type_name::<S::Part2>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` from input previously returned by [`Day::parse`].
+    pub fn solve(&self, part: u8, input: &dyn Any) -> Result<String> {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => Err(anyhow!("invalid part: {}", part)),
+        }
+    }
+
+    /// Records the day's simulation from input previously returned by [`Day::parse`], or returns
+    /// `None` if the day has nothing to show.
+    pub fn visualize(&self, input: &dyn Any) -> Result<Option<viz::Recording>> {
+        (self.visualize)(input)
+    }
+
+    /// Generates an input of the given size from `seed`, or returns `None` if the day has no
+    /// generator.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generate)(size, &mut generate::Rng::new(seed))
+    }
+
+    /// The name of the Rust type `part` answers with, before it is converted to a string.
+    pub fn answer_type(&self, part: u8) -> Result<&'static str> {
+        match part {
+            1 => Ok((self.part1_type)()),
+            2 => Ok((self.part2_type)()),
+            _ => Err(anyhow!("invalid part: {}", part)),
+        }
+    }
+}
+
+/// Declares `pub const DAY: common::Day` for a day crate from its `INPUT` and [`Solution`].
+#[macro_export]
+macro_rules! register {
+    ($number:expr, $solution:ty) => {
+        pub const DAY: $crate::Day = $crate::Day::new::<$solution>($number, INPUT);
+    };
+}
+
+/// Runs a day binary with [`RunOptions::run`], printing the error and exiting with a failure
+/// status if the input can't be loaded or solved.
 #[macro_export]
 macro_rules! instrument {
-    ($part1:expr, $part2:expr) => {
-        let mut now = std::time::Instant::now();
-        println!("Part 1: {}", $part1);
-        println!("(elapsed: {:?})", now.elapsed());
-        now = std::time::Instant::now();
-        println!("");
-        println!("Part 2: {}", $part2);
-        println!("(elapsed: {:?})", now.elapsed());
+    ($solution:ty, $input:expr) => {
+        if let Err(err) = $crate::RunOptions::from_args().run::<$solution>($input) {
+            eprintln!("Error: {:?}", err);
+            ::std::process::exit(1);
+        }
     };
 }
@@ -0,0 +1,189 @@
+//! Heap allocation counting, enabled by the `alloc-stats` feature, which installs
+//! [`CountingAlloc`] as the global allocator of any binary linking `common`.
+//!
+//! Counters are kept per thread, so a step only sees the allocations it made itself even when
+//! other parts are solved on other threads at the same time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display, Formatter};
+
+/// The system allocator, counting every allocation made through it.
+pub struct CountingAlloc;
+
+struct Counters {
+    allocations: Cell<u64>,
+    allocated: Cell<u64>,
+    /// Signed because memory allocated on one thread can be freed on another.
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    // Const-initialized and without a destructor, so using it from the allocator can't allocate.
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            allocated: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        if allocated > 0 {
+            counters.allocations.set(counters.allocations.get() + 1);
+            counters
+                .allocated
+                .set(counters.allocated.get() + allocated as u64);
+        }
+        let live = counters.live.get() + allocated as i64 - freed as i64;
+        counters.live.set(live);
+        counters.peak.set(counters.peak.get().max(live));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+}
+
+/// Heap usage of one run of a step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting every reallocation as a new one.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub allocated: u64,
+    /// Most bytes the step had allocated and not yet freed at any one time.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(allocations: {}, allocated: {}, peak: {})",
+            self.allocations,
+            format_bytes(self.allocated),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Whether [`CountingAlloc`] is the global allocator, i.e. `common` was built with the
+/// `alloc-stats` feature.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Runs `f`, returning the heap usage of the current thread while it ran, or `None` when
+/// allocations aren't being counted.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if enabled() {
+        let (value, stats) = count(f);
+        (value, Some(stats))
+    } else {
+        (f(), None)
+    }
+}
+
+fn count<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (allocations, allocated, live) = COUNTERS.with(|counters| {
+        counters.peak.set(counters.live.get());
+        (
+            counters.allocations.get(),
+            counters.allocated.get(),
+            counters.live.get(),
+        )
+    });
+    let value = f();
+    let stats = COUNTERS.with(|counters| AllocStats {
+        allocations: counters.allocations.get() - allocations,
+        allocated: counters.allocated.get() - allocated,
+        peak: (counters.peak.get() - live).max(0) as u64,
+    });
+    (value, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_peak() {
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let big = Layout::from_size_align(1000, 8).unwrap();
+        let ((), stats) = count(|| unsafe {
+            let a = CountingAlloc.alloc(small);
+            CountingAlloc.dealloc(a, small);
+            let b = CountingAlloc.alloc_zeroed(big);
+            let b = CountingAlloc.realloc(b, big, 3000);
+            CountingAlloc.dealloc(b, Layout::from_size_align(3000, 8).unwrap());
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                allocated: 4100,
+                peak: 3000,
+            }
+        );
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = AllocStats {
+            allocations: 12,
+            allocated: 5 * 1024 * 1024 + 512 * 1024,
+            peak: 900,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "(allocations: 12, allocated: 5.5 MiB, peak: 900 B)"
+        );
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+    }
+}
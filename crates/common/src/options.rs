@@ -0,0 +1,128 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::bench::BenchOptions;
+use crate::input::{normalize_input, InputSource};
+use crate::viz::VizOptions;
+use crate::Solution;
+
+/// Command line options shared by the day binaries and the `aoc` runner.
+#[derive(Debug, Default, clap::Args)]
+pub struct RunOptions {
+    /// Read puzzle input from PATH, or from stdin if PATH is `-`, instead of the embedded input
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<InputSource>,
+
+    #[command(flatten)]
+    pub bench: BenchOptions,
+
+    #[command(flatten)]
+    pub viz: VizOptions,
+}
+
+/// Runs a single day's solution.
+#[derive(Debug, Parser)]
+struct DayArgs {
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+impl RunOptions {
+    /// Parses the options from the command line of a day binary.
+    pub fn from_args() -> Self {
+        DayArgs::parse().options
+    }
+
+    /// Reads the selected input, falling back to `embedded` when no `--input` was given, and
+    /// normalizes it with [`normalize_input`]. Every runner loads input through here, so parsers
+    /// never see `\r\n` or a byte order mark.
+    pub fn load_input(&self, embedded: &str) -> Result<String> {
+        let input = match &self.input {
+            Some(source) => source.read()?,
+            None => embedded.to_string(),
+        };
+        Ok(normalize_input(&input))
+    }
+
+    /// Solves both parts of `S` and prints the answers with their timings, then shows the
+    /// visualization if one was asked for. This is what [`instrument!`](crate::instrument!)
+    /// runs in a day binary.
+    pub fn run<S: Solution>(&self, embedded: &str) -> Result<()> {
+        let raw_input = self.load_input(embedded)?;
+        let (input, timing) = self.bench.measure(|| Ok(S::parse(&raw_input)?))?;
+        println!("Parsed input");
+        println!("{}", timing);
+        println!();
+        let (answer, timing) = self.bench.measure(|| Ok(S::part1(&input)?))?;
+        println!("Part 1: {}", answer);
+        println!("{}", timing);
+        println!();
+        let (answer, timing) = self.bench.measure(|| Ok(S::part2(&input)?))?;
+        println!("Part 2: {}", answer);
+        println!("{}", timing);
+        if self.viz.format.is_some() {
+            match S::visualize(&input)? {
+                Some(recording) => self.viz.show(&recording)?,
+                None => eprintln!("This day has nothing to visualize"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_embedded_input() {
+        let options = RunOptions::default();
+        assert_eq!(options.load_input("1\n2\n").unwrap(), "1\n2\n");
+        assert_eq!(options.load_input("1\r\n2").unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn parses_bench_options() {
+        let options = DayArgs::try_parse_from(["day01", "--bench", "--budget", "250ms"])
+            .unwrap()
+            .options;
+        assert!(options.bench.bench);
+        assert_eq!(options.bench.warmup, 3);
+        assert_eq!(
+            options.bench.budget,
+            Some(std::time::Duration::from_millis(250))
+        );
+        assert!(DayArgs::try_parse_from(["day01", "--iterations", "10"]).is_err());
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> crate::AocResult<u32> {
+            Ok(crate::parse::Span::new(input).parse()?)
+        }
+
+        fn part1(count: &u32) -> crate::AocResult<u32> {
+            Ok(*count)
+        }
+
+        fn part2(count: &u32) -> crate::AocResult<u32> {
+            Ok(*count)
+        }
+    }
+
+    #[test]
+    fn returns_errors_from_runs() {
+        let options = RunOptions::default();
+        assert!(options.run::<Count>("3\n").is_ok());
+        let err = options.run::<Count>("three\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::AocError>(),
+            Some(crate::AocError::MalformedInput(_))
+        ));
+    }
+}
@@ -0,0 +1,278 @@
+//! Input parsing helpers that remember where in the input each piece came from, so a failure
+//! can name the line, the column and the text that didn't parse.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// A piece of input that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input the text starts on, counting from 1.
+    pub line: usize,
+    /// Column of the line the text starts at, in characters and counting from 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (at {:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A slice of the puzzle input that knows where it sits in the whole input.
+///
+/// The methods mirror the `str` methods the days used to chain, but every piece they return is
+/// a `Span` too, and every failure is a [`ParseError`] pointing at the piece that caused it.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    source: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of `input`.
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            source: input,
+            start: 0,
+            end: input.len(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        &self.source[self.start..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Line and column where this span starts, both counting from 1.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.start];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error about the text of this span.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        let (line, column) = self.position();
+        ParseError {
+            line,
+            column,
+            text: self.as_str().to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// The span of `text`, which has to be a slice of this span's text.
+    fn sub(&self, text: &'a str) -> Self {
+        let start = text.as_ptr() as usize - self.source.as_ptr() as usize;
+        Self {
+            source: self.source,
+            start,
+            end: start + text.len(),
+        }
+    }
+
+    pub fn trim(self) -> Self {
+        self.sub(self.as_str().trim())
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.as_str().lines().map(move |line| self.sub(line))
+    }
+
+    /// The blocks of lines separated by blank lines, trimmed.
+    pub fn sections(self) -> impl Iterator<Item = Span<'a>> {
+        self.as_str()
+            .split("\n\n")
+            .map(move |section| self.sub(section).trim())
+            .filter(|section| !section.is_empty())
+    }
+
+    /// Exactly `N` sections, such as the dots and the folds of a transparent paper manual.
+    pub fn sections_exact<const N: usize>(self) -> Result<[Span<'a>; N], ParseError> {
+        let sections: Vec<Span<'a>> = self.sections().collect();
+        let found = sections.len();
+        sections.try_into().map_err(|_| {
+            self.error(format!(
+                "expected {} sections separated by blank lines, found {}",
+                N, found
+            ))
+        })
+    }
+
+    /// The pieces between `separator`s, trimmed.
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.as_str()
+            .split(separator)
+            .map(move |piece| self.sub(piece).trim())
+    }
+
+    pub fn words(self) -> impl Iterator<Item = Span<'a>> {
+        self.as_str()
+            .split_whitespace()
+            .map(move |word| self.sub(word))
+    }
+
+    /// Every character, with the span of just that character to point errors at.
+    pub fn chars(self) -> impl Iterator<Item = (char, Span<'a>)> {
+        self.as_str()
+            .char_indices()
+            .map(move |(i, c)| (c, self.sub(&self.as_str()[i..i + c.len_utf8()])))
+    }
+
+    /// The trimmed text before and after the first `separator`, as in `a -> b` or `a | b`.
+    pub fn split_once(self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (before, after) = self
+            .as_str()
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected {:?}", separator)))?;
+        Ok((self.sub(before).trim(), self.sub(after).trim()))
+    }
+
+    /// Parses the trimmed text with its `FromStr` implementation.
+    pub fn parse<T>(self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let span = self.trim();
+        span.as_str().parse().map_err(|err| span.error(err))
+    }
+
+    /// Parses `a -> b`-style pairs, with `separator` between the two values.
+    pub fn pair<A, B>(self, separator: &str) -> Result<(A, B), ParseError>
+    where
+        A: FromStr,
+        A::Err: Display,
+        B: FromStr,
+        B::Err: Display,
+    {
+        let (a, b) = self.split_once(separator)?;
+        Ok((a.parse()?, b.parse()?))
+    }
+
+    /// Parses comma separated numbers such as `3,4,3,1,2`.
+    pub fn comma_ints<T>(self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(",").map(Span::parse).collect()
+    }
+
+    /// Parses an `x,y` point.
+    pub fn point(self) -> Result<Point, ParseError> {
+        let (x, y) = self.split_once(",")?;
+        Ok(Point::new(x.parse()?, y.parse()?))
+    }
+
+    /// Parses one grid row per line, turning every character into a cell with `cell`, which
+    /// explains what it expected when it rejects a character. Rows must all be as wide as the
+    /// first one.
+    pub fn grid<T>(
+        self,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in self.lines() {
+            let line = line.sub(line.as_str().trim_end());
+            let row_start = cells.len();
+            for (c, c_span) in line.chars() {
+                cells.push(cell(c).map_err(|err| c_span.error(err))?);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(format!(
+                        "row is {} cells wide, expected {}",
+                        row_width, width
+                    )))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        // Every row was checked to be as wide as the first, so the cells always fill the grid.
+        Grid::new(width.unwrap_or(0), height, cells)
+            .map_err(|_| self.error("rows don't form a rectangular grid"))
+    }
+
+    /// Parses a grid of single digits, such as a height or risk map.
+    pub fn digit_grid<T: From<u8>>(self) -> Result<Grid<T>, ParseError> {
+        self.grid(|c| {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| "expected a digit".to_string())?;
+            Ok(T::from(digit as u8))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sections_pairs_and_points() {
+        let input = "7,4,9\n\nab -> c\nde -> f\n\n0,9 -> 5,9\n";
+        let [draws, rules, vents] = Span::new(input).sections_exact().unwrap();
+        assert_eq!(draws.comma_ints::<u8>().unwrap(), vec![7, 4, 9]);
+        let rules: Vec<(String, char)> = rules
+            .lines()
+            .map(|line| line.pair(" -> "))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rules[1], ("de".to_string(), 'f'));
+        let (start, end) = vents.split_once("->").unwrap();
+        assert_eq!(
+            (start.point().unwrap(), end.point().unwrap()),
+            (Point::new(0, 9), Point::new(5, 9))
+        );
+        assert!(Span::new(input).sections_exact::<2>().is_err());
+    }
+
+    #[test]
+    fn reports_positions() {
+        let input = "1,2\n3,x4\n";
+        let err = Span::new(input)
+            .lines()
+            .map(Span::point)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x4"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: invalid digit found in string (at \"x4\")"
+        );
+
+        let err = Span::new("12\n3\n").digit_grid::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Span::new("12\n3a\n").digit_grid::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "a"));
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{AocError, AocResult};
+use crate::parse::Span;
+
+/// A point, or the vector between two points, on the integer plane.
+///
+/// `y` grows downwards like the lines of a puzzle input, so [`Point::UP`] is `(0, -1)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// [`Point`] under the name that reads better for directions and offsets.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// The orthogonal directions, clockwise from up.
+    pub const DIRECTIONS4: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+    /// The orthogonal and diagonal directions, clockwise from up.
+    pub const DIRECTIONS8: [Point; 8] = [
+        Point::UP,
+        Point::new(1, -1),
+        Point::RIGHT,
+        Point::new(1, 1),
+        Point::DOWN,
+        Point::new(-1, 1),
+        Point::LEFT,
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance when moving only orthogonally.
+    pub fn manhattan_distance(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal moves are allowed too.
+    pub fn chebyshev_distance(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Rotates a quarter turn clockwise around the origin, as seen with `y` growing downwards.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The step of at most one in each axis that moves towards the direction of `self`.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Point::DIRECTIONS4.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Point::DIRECTIONS8.into_iter().map(move |d| self + d)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: i32) -> Point {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Div<i32> for Point {
+    type Output = Point;
+
+    fn div(self, scalar: i32) -> Point {
+        Point::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl FromStr for Point {
+    type Err = AocError;
+
+    /// Parses `x,y`, with the same errors as [`Span::point`].
+    fn from_str(s: &str) -> AocResult<Self> {
+        Ok(Span::new(s).point()?)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_arithmetic_and_distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, -4));
+        assert_eq!(-a * 3, Point::new(-3, -6));
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn rotates_and_parses() {
+        assert_eq!(Point::UP.rotate_right(), Point::RIGHT);
+        assert_eq!(Point::UP.rotate_left(), Point::LEFT);
+        assert_eq!(
+            Point::new(2, 1).rotate_right().rotate_left(),
+            Point::new(2, 1)
+        );
+        assert_eq!("6,10".parse::<Point>().unwrap(), Point::new(6, 10));
+        assert_eq!(Point::new(-3, 4).to_string(), "-3,4");
+        assert!("6 10".parse::<Point>().is_err());
+        let err = "6,x".parse::<Point>().unwrap_err();
+        assert!(matches!(err, AocError::MalformedInput(err) if err.column == 3));
+    }
+}
@@ -0,0 +1,232 @@
+use anyhow::Result;
+use std::io::{self, Write};
+
+use crate::bench::{Measurement, Timing};
+use crate::memory::AllocStats;
+use crate::spans::SpanTiming;
+use crate::{Day, RunOptions};
+
+/// How the `aoc` runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human-readable answers and timings
+    #[default]
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// A CSV table with one row per part
+    Csv,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub answer_type: &'static str,
+    pub timing: Timing,
+    /// Heap usage of solving the part, if built with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Time spent in each span while solving the part, if built with the `spans` feature.
+    pub spans: Vec<SpanTiming>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Timing,
+    pub parse_alloc: Option<AllocStats>,
+    pub parse_spans: Vec<SpanTiming>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Parses the selected input for `day` once and solves each of `parts` from it.
+pub fn run_day(day: &Day, parts: &[u8], options: &RunOptions) -> Result<DayReport> {
+    run_day_on(day, &options.load_input(day.input)?, parts, options)
+}
+
+/// Like [`run_day`], for input that was already loaded, so that a caller that needs it again,
+/// e.g. to visualize it, doesn't have to read stdin twice.
+pub fn run_day_on(
+    day: &Day,
+    raw_input: &str,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Result<DayReport> {
+    let (input, parse) = options.bench.measure(|| day.parse(raw_input))?;
+
+    let mut part_reports = vec![];
+    for &part in parts {
+        let (answer, solve) = options.bench.measure(|| day.solve(part, input.as_ref()))?;
+        part_reports.push(PartReport {
+            part,
+            answer,
+            answer_type: day.answer_type(part)?,
+            timing: solve.timing,
+            alloc: solve.alloc,
+            spans: solve.spans,
+        });
+    }
+
+    Ok(DayReport {
+        day: day.number,
+        parse: parse.timing,
+        parse_alloc: parse.alloc,
+        parse_spans: parse.spans,
+        parts: part_reports,
+    })
+}
+
+const CSV_HEADER: &str =
+    "day,part,answer,answer_type,parse_ns,runs,min_ns,mean_ns,median_ns,stddev_ns";
+
+pub fn write_text(out: &mut impl Write, report: &DayReport) -> io::Result<()> {
+    writeln!(out, "Day {}", report.day)?;
+    writeln!(out, "Parsed input")?;
+    let parse = Measurement {
+        timing: report.parse,
+        alloc: report.parse_alloc,
+        spans: report.parse_spans.clone(),
+    };
+    writeln!(out, "{}", parse)?;
+    for part in &report.parts {
+        writeln!(out)?;
+        writeln!(out, "Part {}: {}", part.part, part.answer)?;
+        let solve = Measurement {
+            timing: part.timing,
+            alloc: part.alloc,
+            spans: part.spans.clone(),
+        };
+        writeln!(out, "{}", solve)?;
+    }
+    Ok(())
+}
+
+pub fn write_json(out: &mut impl Write, reports: &[DayReport]) -> io::Result<()> {
+    let mut records = vec![];
+    for report in reports {
+        let parse = report.parse.stats();
+        for part in &report.parts {
+            let stats = part.timing.stats();
+            records.push(format!(
+                "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"parse_ns\":{},\
+                 \"runs\":{},\"min_ns\":{},\"mean_ns\":{},\"median_ns\":{},\"stddev_ns\":{}}}",
+                report.day,
+                part.part,
+                json_string(&part.answer),
+                json_string(part.answer_type),
+                parse.mean.as_nanos(),
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.median.as_nanos(),
+                stats.stddev.as_nanos(),
+            ));
+        }
+    }
+
+    if records.is_empty() {
+        return writeln!(out, "[]");
+    }
+    writeln!(out, "[")?;
+    writeln!(out, "  {}", records.join(",\n  "))?;
+    writeln!(out, "]")
+}
+
+pub fn write_csv(out: &mut impl Write, reports: &[DayReport]) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER)?;
+    for report in reports {
+        let parse = report.parse.stats();
+        for part in &report.parts {
+            let stats = part.timing.stats();
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{}",
+                report.day,
+                part.part,
+                csv_field(&part.answer),
+                csv_field(part.answer_type),
+                parse.mean.as_nanos(),
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.median.as_nanos(),
+                stats.stddev.as_nanos(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn report() -> DayReport {
+        DayReport {
+            day: 13,
+            parse: Timing::Single(Duration::from_micros(5)),
+            parse_alloc: None,
+            parse_spans: vec![],
+            parts: vec![PartReport {
+                part: 2,
+                answer: "\n#.\n\"#\"".to_string(),
+                answer_type: "alloc::string::String",
+                timing: Timing::Single(Duration::from_nanos(1200)),
+                alloc: None,
+                spans: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn writes_json() {
+        let mut out = vec![];
+        write_json(&mut out, &[report()]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[\n  {\"day\":13,\"part\":2,\"answer\":\"\\n#.\\n\\\"#\\\"\",\
+             \"answer_type\":\"alloc::string::String\",\"parse_ns\":5000,\"runs\":1,\
+             \"min_ns\":1200,\"mean_ns\":1200,\"median_ns\":1200,\"stddev_ns\":0}\n]\n"
+        );
+    }
+
+    #[test]
+    fn writes_csv() {
+        let mut out = vec![];
+        write_csv(&mut out, &[report()]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "{}\n13,2,\"\n#.\n\"\"#\"\"\",alloc::string::String,5000,1,1200,1200,1200,0\n",
+                CSV_HEADER
+            )
+        );
+    }
+}
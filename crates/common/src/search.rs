@@ -0,0 +1,277 @@
+//! Graph searches over implicit graphs: nodes are discovered through a `neighbors` closure
+//! instead of being stored up front.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Total cost of the path; the number of steps for unweighted searches.
+    pub cost: C,
+    /// The nodes from the start to the goal, both included.
+    pub nodes: Vec<N>,
+}
+
+/// Nodes discovered by a search, each with the index of the node it was reached from.
+struct Visited<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        let mut indices = HashMap::new();
+        indices.insert(start.clone(), 0);
+        Self {
+            nodes: vec![(start, None, cost)],
+            indices,
+        }
+    }
+
+    fn insert(&mut self, node: N, parent: usize, cost: C) -> usize {
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push((node, Some(parent), cost));
+        index
+    }
+
+    fn path(&self, index: usize) -> Path<N, C> {
+        let cost = self.nodes[index].2;
+        let mut nodes = vec![];
+        let mut current = Some(index);
+        while let Some(index) = current {
+            nodes.push(self.nodes[index].0.clone());
+            current = self.nodes[index].1;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth-first search for the path with the fewest steps from `start` to a node that
+/// satisfies `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        for next in neighbors(&node) {
+            if !visited.indices.contains_key(&next) {
+                queue.push_back(visited.insert(next, index, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Depth-first search for any path from `start` to a node that satisfies `is_goal`. The path
+/// found isn't necessarily the shortest one.
+pub fn dfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut stack = vec![0];
+    while let Some(index) = stack.pop() {
+        let (node, _, steps) = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        for next in neighbors(&node) {
+            if !visited.indices.contains_key(&next) {
+                stack.push(visited.insert(next, index, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start`, `start` included.
+pub fn reachable<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for next in neighbors(&node) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+    visited
+}
+
+/// Every path from `start` that ends at the first node satisfying `is_goal`, found depth-first.
+///
+/// Nodes may repeat within a path, so `neighbors` gets the whole path so far to decide where it
+/// can go next. It has to rule out cycles itself.
+pub fn all_paths<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&[N]) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Vec<Vec<N>>
+where
+    N: Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = vec![];
+    let mut stack = vec![vec![start]];
+    while let Some(path) = stack.pop() {
+        if path.last().is_some_and(&mut is_goal) {
+            paths.push(path);
+            continue;
+        }
+        for next in neighbors(&path) {
+            let mut next_path = path.clone();
+            next_path.push(next);
+            stack.push(next_path);
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm: the cheapest path from `start` to a node that satisfies `is_goal`,
+/// where `neighbors` yields each next node with the cost of the step to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A*: like [`dijkstra`], but expanding nodes in order of their cost plus `heuristic`, an
+/// estimate of the remaining cost that must never overestimate it.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((heuristic(&start), C::default(), 0)));
+    let mut visited = Visited::new(start, C::default());
+
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        let (node, _, best) = visited.nodes[index].clone();
+        if cost > best {
+            // A cheaper way to this node was found after this entry was queued.
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            let next_index = match visited.indices.get(&next) {
+                Some(&next_index) if visited.nodes[next_index].2 <= next_cost => continue,
+                Some(&next_index) => {
+                    visited.nodes[next_index].1 = Some(index);
+                    visited.nodes[next_index].2 = next_cost;
+                    next_index
+                }
+                None => visited.insert(next.clone(), index, next_cost),
+            };
+            open.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+.#...
+.#.#.
+...#.
+##.#.
+.....";
+
+    #[test]
+    fn finds_shortest_paths() {
+        let maze = Grid::parse(MAZE, |c| Ok(c == '#')).unwrap();
+        let start = Point::new(0, 0);
+        let goal = Point::new(4, 0);
+        let open = |point: &Point| {
+            point
+                .neighbors4()
+                .filter(|&next| maze.get_point(next) == Some(&false))
+                .collect::<Vec<_>>()
+        };
+
+        let path = bfs(start, open, |&point| point == goal).unwrap();
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.nodes.len(), 9);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&goal));
+
+        let weighted = |point: &Point| open(point).into_iter().map(|next| (next, 2));
+        let path = dijkstra(start, weighted, |&point| point == goal).unwrap();
+        assert_eq!(path.cost, 16);
+        let path = astar(
+            start,
+            weighted,
+            |point| point.manhattan_distance(goal),
+            |&point| point == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 16);
+
+        assert!(dfs(start, open, |&point| point == goal).is_some());
+        assert!(bfs(start, open, |&point| point == Point::new(1, 0)).is_none());
+    }
+
+    #[test]
+    fn enumerates_paths_and_reachable_nodes() {
+        // 1 -> 2 -> 4, 1 -> 3 -> 4, 1 -> 4, and 5 on its own.
+        let edges = |node: &u32| match node {
+            1 => vec![2, 3, 4],
+            2 | 3 => vec![4],
+            _ => vec![],
+        };
+        let paths = all_paths(1, |path: &[u32]| edges(path.last().unwrap()), |&n| n == 4);
+        assert_eq!(paths.len(), 3);
+        assert!(paths.contains(&vec![1, 3, 4]));
+        assert_eq!(reachable(1, edges), HashSet::from([1, 2, 3, 4]));
+    }
+}
@@ -0,0 +1,230 @@
+//! Timing of the phases inside a step, enabled by the `spans` feature.
+//!
+//! Solvers mark a phase with a guard that times it until it is dropped:
+//!
+//! ```
+//! fn solve() {
+//!     let _span = common::span!("expand grid");
+//!     // ...
+//! }
+//! ```
+//!
+//! Spans opened while another one is open nest under it, and every call of the same span under
+//! the same parent adds up into one entry. Without the feature, [`enter`] does nothing and the
+//! guard is an empty type, so spans can stay in the code for good.
+
+use std::time::Duration;
+
+/// Time spent in one span, with the spans nested in it following it at a higher `depth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanTiming {
+    pub name: &'static str,
+    /// Number of spans this one is nested in, 0 for the outermost ones.
+    pub depth: usize,
+    /// How many times the span was entered.
+    pub calls: u32,
+    /// Total time across every call.
+    pub elapsed: Duration,
+}
+
+/// Times a span until dropped. Returned by [`enter`] and [`span!`](crate::span!).
+#[must_use = "the span ends as soon as the guard is dropped"]
+pub struct SpanGuard {
+    #[cfg(feature = "spans")]
+    recording: Option<(usize, std::time::Instant)>,
+}
+
+/// Whether spans are recorded, i.e. `common` was built with the `spans` feature.
+pub fn enabled() -> bool {
+    cfg!(feature = "spans")
+}
+
+/// Opens the span `name`, nested in the innermost span currently open on this thread.
+///
+/// Spans are only recorded while [`collect`] runs.
+#[inline(always)]
+pub fn enter(name: &'static str) -> SpanGuard {
+    #[cfg(feature = "spans")]
+    {
+        SpanGuard {
+            recording: recorder::enter(name).map(|index| (index, std::time::Instant::now())),
+        }
+    }
+    #[cfg(not(feature = "spans"))]
+    {
+        let _ = name;
+        SpanGuard {}
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if let Some((index, started)) = self.recording {
+            recorder::exit(index, started.elapsed());
+        }
+    }
+}
+
+/// Runs `f`, returning the spans it went through in the order they were first entered, each
+/// followed by the spans nested in it. Without the `spans` feature the list is always empty.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<SpanTiming>) {
+    #[cfg(feature = "spans")]
+    {
+        recorder::start();
+        let value = f();
+        (value, recorder::finish())
+    }
+    #[cfg(not(feature = "spans"))]
+    {
+        (f(), vec![])
+    }
+}
+
+#[cfg(feature = "spans")]
+mod recorder {
+    use super::SpanTiming;
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    struct Node {
+        name: &'static str,
+        calls: u32,
+        elapsed: Duration,
+        children: Vec<usize>,
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        nodes: Vec<Node>,
+        roots: Vec<usize>,
+        /// The spans currently open, innermost last.
+        open: Vec<usize>,
+    }
+
+    thread_local! {
+        static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+    }
+
+    pub fn start() {
+        RECORDER.with(|recorder| *recorder.borrow_mut() = Some(Recorder::default()));
+    }
+
+    pub fn enter(name: &'static str) -> Option<usize> {
+        RECORDER.with(|recorder| {
+            let mut recorder = recorder.borrow_mut();
+            let recorder = recorder.as_mut()?;
+            let siblings = match recorder.open.last() {
+                Some(&parent) => &recorder.nodes[parent].children,
+                None => &recorder.roots,
+            };
+            let index = match siblings
+                .iter()
+                .copied()
+                .find(|&sibling| recorder.nodes[sibling].name == name)
+            {
+                Some(index) => index,
+                None => {
+                    let index = recorder.nodes.len();
+                    recorder.nodes.push(Node {
+                        name,
+                        calls: 0,
+                        elapsed: Duration::ZERO,
+                        children: vec![],
+                    });
+                    match recorder.open.last() {
+                        Some(&parent) => recorder.nodes[parent].children.push(index),
+                        None => recorder.roots.push(index),
+                    }
+                    index
+                }
+            };
+            recorder.open.push(index);
+            Some(index)
+        })
+    }
+
+    pub fn exit(index: usize, elapsed: Duration) {
+        RECORDER.with(|recorder| {
+            // A span can outlive the collection it was opened in, in which case the index
+            // belongs to the previous recording.
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                let Some(node) = recorder.nodes.get_mut(index) else {
+                    return;
+                };
+                node.calls += 1;
+                node.elapsed += elapsed;
+                if let Some(position) = recorder.open.iter().rposition(|&open| open == index) {
+                    recorder.open.remove(position);
+                }
+            }
+        });
+    }
+
+    pub fn finish() -> Vec<SpanTiming> {
+        let Some(recorder) = RECORDER.with(|recorder| recorder.borrow_mut().take()) else {
+            return vec![];
+        };
+        let mut timings = vec![];
+        let mut stack: Vec<(usize, usize)> =
+            recorder.roots.iter().rev().map(|&root| (root, 0)).collect();
+        while let Some((index, depth)) = stack.pop() {
+            let node = &recorder.nodes[index];
+            timings.push(SpanTiming {
+                name: node.name,
+                depth,
+                calls: node.calls,
+                elapsed: node.elapsed,
+            });
+            stack.extend(node.children.iter().rev().map(|&child| (child, depth + 1)));
+        }
+        timings
+    }
+}
+
+/// Opens a span named `name` that lasts until the returned guard is dropped. See
+/// [`spans`](crate::spans).
+#[macro_export]
+macro_rules! span {
+    ($name:expr) => {
+        $crate::spans::enter($name)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nests_and_merges_spans() {
+        let ((), spans) = collect(|| {
+            {
+                let _outer = span!("outer");
+                for _ in 0..3 {
+                    let _inner = span!("inner");
+                }
+            }
+            let _other = span!("other");
+        });
+        let names: Vec<(&str, usize, u32)> = spans
+            .iter()
+            .map(|span| (span.name, span.depth, span.calls))
+            .collect();
+        if enabled() {
+            assert_eq!(
+                names,
+                vec![("outer", 0, 1), ("inner", 1, 3), ("other", 0, 1)]
+            );
+            assert!(spans[0].elapsed >= spans[1].elapsed);
+        } else {
+            assert!(names.is_empty());
+        }
+    }
+
+    #[test]
+    fn ignores_spans_outside_of_collect() {
+        let _ignored = span!("ignored");
+        let ((), spans) = collect(|| {});
+        assert!(spans.is_empty());
+    }
+}
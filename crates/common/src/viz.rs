@@ -0,0 +1,325 @@
+//! Frame recording for simulations, so a day can show how its state evolves instead of just the
+//! answer it ends with.
+//!
+//! A [`Solution`](crate::Solution) records a [`Recording`] in its `visualize` step, pushing a
+//! snapshot of the grid after every step, and the runner plays it back in the format chosen with
+//! `--viz`: an animation in the terminal, a plain text dump of every frame, or an animated GIF.
+
+use anyhow::{bail, Context, Result};
+use std::borrow::Cow;
+use std::collections::hash_map::{Entry, HashMap};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use crate::bench::parse_duration;
+use crate::grid::Grid;
+
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// Side of the square of pixels each cell becomes in a GIF.
+const GIF_CELL_SIZE: usize = 4;
+
+const BACKGROUND: Rgb = Rgb(0, 0, 0);
+const FOREGROUND: Rgb = Rgb(220, 220, 220);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// One cell of a frame: the character it shows in text, and optionally the colour it is drawn
+/// in. Cells without a colour are blank in images if their glyph is a space or a `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Option<Rgb>,
+}
+
+impl Cell {
+    pub fn new(glyph: char) -> Self {
+        Self { glyph, color: None }
+    }
+
+    pub fn colored(glyph: char, color: Rgb) -> Self {
+        Self {
+            glyph,
+            color: Some(color),
+        }
+    }
+
+    /// The colour of the cell in images.
+    fn pixel(&self) -> Rgb {
+        match (self.color, self.glyph) {
+            (Some(color), _) => color,
+            (None, ' ' | '.') => BACKGROUND,
+            (None, _) => FOREGROUND,
+        }
+    }
+}
+
+/// A snapshot of a simulation, with a caption such as the number of the step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+/// The frames of a simulation, in order. Frames don't have to be the same size.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn push(&mut self, caption: impl Into<String>, cells: Grid<Cell>) {
+        self.frames.push(Frame {
+            caption: caption.into(),
+            cells,
+        });
+    }
+
+    /// Pushes a snapshot of `grid`, turning each of its cells into a [`Cell`] with `cell`.
+    pub fn push_grid<T>(
+        &mut self,
+        caption: impl Into<String>,
+        grid: &Grid<T>,
+        cell: impl FnMut(&T) -> Cell,
+    ) {
+        self.push(caption, grid.map(cell));
+    }
+
+    /// Writes every frame as plain text: its caption, then one line per row of glyphs, with a
+    /// blank line after each frame.
+    pub fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        for frame in &self.frames {
+            writeln!(out, "{}", frame.caption)?;
+            for row in frame.cells.rows() {
+                let row: String = row.iter().map(|cell| cell.glyph).collect();
+                writeln!(out, "{}", row)?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    /// Plays the frames in a terminal, redrawing the screen every `delay` with cells in their
+    /// colours.
+    pub fn write_ansi(&self, out: &mut impl Write, delay: Duration) -> io::Result<()> {
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i > 0 {
+                thread::sleep(delay);
+            }
+            // Clear the screen and move the cursor to its top left corner.
+            writeln!(out, "\x1b[2J\x1b[H{}", frame.caption)?;
+            for row in frame.cells.rows() {
+                for cell in row {
+                    match cell.color {
+                        Some(Rgb(r, g, b)) => {
+                            write!(out, "\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, cell.glyph)?
+                        }
+                        None => write!(out, "{}", cell.glyph)?,
+                    }
+                }
+                writeln!(out)?;
+            }
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the frames as a looping animated GIF showing each for `delay`, with every cell a
+    /// square of pixels. Frames smaller than the largest one are padded with background.
+    pub fn write_gif(&self, out: impl Write, delay: Duration) -> Result<()> {
+        let width = self.frames.iter().map(|frame| frame.cells.width()).max();
+        let height = self.frames.iter().map(|frame| frame.cells.height()).max();
+        let (Some(width), Some(height)) = (width, height) else {
+            bail!("there are no frames to encode");
+        };
+        let (Ok(pixel_width), Ok(pixel_height)) = (
+            u16::try_from(width * GIF_CELL_SIZE),
+            u16::try_from(height * GIF_CELL_SIZE),
+        ) else {
+            bail!("{}x{} cells don't fit in a GIF", width, height);
+        };
+
+        let mut palette = vec![BACKGROUND];
+        let mut indices = HashMap::from([(BACKGROUND, 0u8)]);
+        for frame in &self.frames {
+            for (_, cell) in frame.cells.cells() {
+                let color = cell.pixel();
+                if let Entry::Vacant(entry) = indices.entry(color) {
+                    let index = u8::try_from(palette.len())
+                        .context("a GIF can't have more than 256 colours")?;
+                    entry.insert(index);
+                    palette.push(color);
+                }
+            }
+        }
+        let palette: Vec<u8> = palette.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+
+        let mut encoder = gif::Encoder::new(out, pixel_width, pixel_height, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        let centiseconds = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+        for frame in &self.frames {
+            let mut pixels = vec![0; usize::from(pixel_width) * usize::from(pixel_height)];
+            for ((x, y), cell) in frame.cells.cells() {
+                let index = indices[&cell.pixel()];
+                for dy in 0..GIF_CELL_SIZE {
+                    let row_start = (y * GIF_CELL_SIZE + dy) * usize::from(pixel_width);
+                    let start = row_start + x * GIF_CELL_SIZE;
+                    pixels[start..start + GIF_CELL_SIZE].fill(index);
+                }
+            }
+            encoder.write_frame(&gif::Frame {
+                width: pixel_width,
+                height: pixel_height,
+                delay: centiseconds,
+                buffer: Cow::Owned(pixels),
+                ..gif::Frame::default()
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// How to play back a [`Recording`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum VizFormat {
+    /// An animation in the terminal
+    Ansi,
+    /// Every frame as plain text
+    Text,
+    /// An animated GIF, written to --viz-output
+    Gif,
+}
+
+/// Options for showing a day's visualization.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct VizOptions {
+    /// Show how the day's simulation evolves after solving it
+    #[arg(id = "viz", long = "viz", value_enum, value_name = "FORMAT")]
+    pub format: Option<VizFormat>,
+
+    /// Write the visualization to PATH instead of stdout
+    #[arg(long, value_name = "PATH", requires = "viz")]
+    pub viz_output: Option<PathBuf>,
+
+    /// How long each frame is shown for [default: 100ms]
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration, requires = "viz")]
+    pub viz_delay: Option<Duration>,
+}
+
+impl VizOptions {
+    /// The options for one of several days shown in one run, which writes to its own file named
+    /// after `--viz-output` and the day, e.g. `out-day05.gif` for `out.gif`, so days don't
+    /// overwrite each other's.
+    pub fn for_day(&self, day: u8) -> Self {
+        let viz_output = self.viz_output.as_ref().map(|path| {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let mut name = format!("{}-day{:02}", stem, day);
+            if let Some(extension) = path.extension() {
+                name.push('.');
+                name.push_str(&extension.to_string_lossy());
+            }
+            path.with_file_name(name)
+        });
+        Self {
+            viz_output,
+            ..self.clone()
+        }
+    }
+
+    /// Writes `recording` in the selected format to the selected output.
+    pub fn show(&self, recording: &Recording) -> Result<()> {
+        let Some(format) = self.format else {
+            return Ok(());
+        };
+        let delay = self.viz_delay.unwrap_or(DEFAULT_DELAY);
+        let mut out: Box<dyn Write> = match &self.viz_output {
+            Some(path) => {
+                Box::new(BufWriter::new(File::create(path).with_context(|| {
+                    format!("failed to create {}", path.display())
+                })?))
+            }
+            None if format == VizFormat::Gif => bail!("--viz gif needs a --viz-output file"),
+            None => Box::new(io::stdout().lock()),
+        };
+        match format {
+            VizFormat::Ansi => recording.write_ansi(&mut out, delay)?,
+            VizFormat::Text => recording.write_text(&mut out)?,
+            VizFormat::Gif => recording.write_gif(&mut out, delay)?,
+        }
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording() -> Recording {
+        let mut recording = Recording::new();
+        let grid = Grid::parse("#.\n.#\n", |c| Ok(c == '#')).unwrap();
+        recording.push_grid("Step 0", &grid, |&on| {
+            if on {
+                Cell::colored('#', Rgb(255, 0, 0))
+            } else {
+                Cell::new('.')
+            }
+        });
+        recording.push("Step 1", Grid::from_fn(1, 1, |_, _| Cell::new('x')));
+        recording
+    }
+
+    #[test]
+    fn writes_text_and_ansi() {
+        let mut out = vec![];
+        recording().write_text(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Step 0\n#.\n.#\n\nStep 1\nx\n\n"
+        );
+
+        let mut out = vec![];
+        recording().write_ansi(&mut out, Duration::ZERO).unwrap();
+        let ansi = String::from_utf8(out).unwrap();
+        assert!(ansi.starts_with("\x1b[2J\x1b[HStep 0\n\x1b[38;2;255;0;0m#\x1b[0m.\n"));
+        assert_eq!(ansi.matches("\x1b[2J").count(), 2);
+    }
+
+    #[test]
+    fn encodes_gifs() {
+        let mut out = vec![];
+        recording()
+            .write_gif(&mut out, Duration::from_millis(50))
+            .unwrap();
+        assert!(out.starts_with(b"GIF89a"));
+        // The logical screen is the largest frame, 2x2 cells of 4x4 pixels.
+        assert_eq!(&out[6..10], &[8, 0, 8, 0]);
+        assert!(Recording::new().write_gif(vec![], DEFAULT_DELAY).is_err());
+    }
+
+    #[test]
+    fn names_outputs_after_days() {
+        let options = VizOptions {
+            format: Some(VizFormat::Gif),
+            viz_output: Some(PathBuf::from("viz/out.gif")),
+            viz_delay: None,
+        };
+        assert_eq!(
+            options.for_day(5).viz_output,
+            Some(PathBuf::from("viz/out-day05.gif"))
+        );
+        assert_eq!(VizOptions::default().for_day(5).viz_output, None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Day 1: Sonar Sweep, counting how often the depth readings of a sonar sweep increase, one by one
+//! and over a sliding window of three.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocResult, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// Solves day 1 with the [`Solution`] trait.
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<i32>> {
+        Ok(Span::new(input)
+            .trim()
+            .lines()
+            .map(Span::parse)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(readings: &Vec<i32>) -> AocResult<i32> {
+        let mut increases = 0;
+        let mut prev_reading: Option<i32> = None;
+        for &reading in readings {
+            if let Some(prev) = prev_reading {
+                if reading > prev {
+                    increases += 1;
+                }
+            }
+            prev_reading = Some(reading);
+        }
+
+        Ok(increases)
+    }
+
+    fn part2(readings: &Vec<i32>) -> AocResult<i32> {
+        let mut increases = 0;
+        let mut prev_sum: Option<i32> = None;
+        for group in readings.windows(3) {
+            let sum = group.iter().sum();
+            if let Some(prev) = prev_sum {
+                if sum > prev {
+                    increases += 1;
+                }
+            }
+            prev_sum = Some(sum);
+        }
+
+        Ok(increases)
+    }
+
+    /// `size` readings of a sea floor that gets deeper with some noise, like the real input.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let mut input = String::new();
+        let mut depth = rng.between(100, 200) as i32;
+        for _ in 0..size {
+            depth = (depth + rng.between(0, 45) as i32 - 20).max(0);
+            input.push_str(&format!("{}\n", depth));
+        }
+        Some(input)
+    }
+}
+
+common::register!(1, Day01);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_a_deepening_sea_floor() {
+        let readings = Day01::parse(&DAY.generate(500, 1).unwrap()).unwrap();
+        assert_eq!(readings.len(), 500);
+        assert!(readings.iter().all(|&depth| depth >= 0));
+        assert!(readings[499] > readings[0]);
+    }
+
+    proptest! {
+        #[test]
+        fn counts_increases_like_comparing_by_index(readings in vec(0i32..10_000, 0..50)) {
+            let text = readings.iter().map(i32::to_string).collect::<Vec<_>>().join("\n");
+            let readings = Day01::parse(&text).unwrap();
+            // Consecutive sums of three share two readings, so only the readings `gap` apart matter.
+            let increases = |gap: usize| {
+                (gap..readings.len())
+                    .filter(|&i| readings[i] > readings[i - gap])
+                    .count() as i32
+            };
+            prop_assert_eq!(Day01::part1(&readings).unwrap(), increases(1));
+            prop_assert_eq!(Day01::part2(&readings).unwrap(), increases(3));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Day 2: Dive!, following the submarine's [`Command`]s, first as plain moves and then with an aim.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// One line of the planned course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Moves forward, and with an aim also down by the aim times the distance.
+    Forward(i32),
+    /// Rises by this much, or lowers the aim by it.
+    Up(i32),
+    /// Sinks by this much, or raises the aim by it.
+    Down(i32),
+}
+
+/// The horizontal position times the depth, which long courses can take past an `i32`.
+fn position_product(horizontal: i64, depth: i64) -> AocResult<i32> {
+    horizontal
+        .checked_mul(depth)
+        .and_then(|product| i32::try_from(product).ok())
+        .ok_or_else(|| AocError::overflow("position times depth doesn't fit in an i32"))
+}
+
+/// Solves day 2 with the [`Solution`] trait.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Vec<Command>> {
+        Span::new(input)
+            .trim()
+            .lines()
+            .map(|line| {
+                let (command, value) = line.split_once(" ")?;
+                let value = value.parse::<i32>()?;
+
+                match command.as_str() {
+                    "forward" => Ok(Command::Forward(value)),
+                    "up" => Ok(Command::Up(value)),
+                    "down" => Ok(Command::Down(value)),
+                    _ => Err(command.error("unknown command").into()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> AocResult<i32> {
+        let mut horizontal: i64 = 0;
+        let mut depth: i64 = 0;
+
+        for &command in commands {
+            match command {
+                Command::Forward(value) => horizontal += i64::from(value),
+                Command::Up(value) => depth -= i64::from(value),
+                Command::Down(value) => depth += i64::from(value),
+            }
+        }
+
+        position_product(horizontal, depth)
+    }
+
+    fn part2(commands: &Vec<Command>) -> AocResult<i32> {
+        let mut horizontal: i64 = 0;
+        let mut depth: i64 = 0;
+        let mut aim: i64 = 0;
+
+        for &command in commands {
+            match command {
+                Command::Forward(value) => {
+                    horizontal += i64::from(value);
+                    depth += aim * i64::from(value);
+                }
+                Command::Up(value) => aim -= i64::from(value),
+                Command::Down(value) => aim += i64::from(value),
+            }
+        }
+
+        position_product(horizontal, depth)
+    }
+
+    /// `size` commands that never take the submarine above the surface.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let mut input = String::new();
+        let mut depth = 0;
+        for _ in 0..size {
+            let value = rng.between(1, 9);
+            let command = match rng.below(3) {
+                0 => "forward",
+                1 if depth >= value => {
+                    depth -= value;
+                    "up"
+                }
+                _ => {
+                    depth += value;
+                    "down"
+                }
+            };
+            input.push_str(&format!("{} {}\n", command, value));
+        }
+        Some(input)
+    }
+}
+
+common::register!(2, Day02);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_courses_that_stay_underwater() {
+        let commands = Day02::parse(&DAY.generate(500, 1).unwrap()).unwrap();
+        assert_eq!(commands.len(), 500);
+        let mut depth = 0;
+        for command in commands {
+            match command {
+                Command::Forward(_) => {}
+                Command::Up(value) => depth -= value,
+                Command::Down(value) => depth += value,
+            }
+            assert!(depth >= 0);
+        }
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let commands = vec![Command::Forward(100_000), Command::Down(100_000)];
+        assert!(Day02::part1(&commands).is_err());
+        assert!(Day02::part2(&commands).is_ok());
+    }
+
+    const COMMANDS: [&str; 3] = ["forward", "up", "down"];
+
+    proptest! {
+        #[test]
+        fn matches_summing_the_commands(commands in vec((0..3usize, 0i32..10), 0..40)) {
+            let text = commands
+                .iter()
+                .map(|&(command, value)| format!("{} {}", COMMANDS[command], value))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let parsed = Day02::parse(&text).unwrap();
+            let total = |command: usize| -> i32 {
+                commands
+                    .iter()
+                    .filter(|&&(c, _)| c == command)
+                    .map(|&(_, value)| value)
+                    .sum()
+            };
+            let aim_before = |i: usize| total_before(&commands[..i]);
+            let depth: i32 = (0..commands.len())
+                .filter(|&i| commands[i].0 == 0)
+                .map(|i| commands[i].1 * aim_before(i))
+                .sum();
+
+            prop_assert_eq!(Day02::part1(&parsed).unwrap(), total(0) * (total(2) - total(1)));
+            prop_assert_eq!(Day02::part2(&parsed).unwrap(), total(0) * depth);
+        }
+    }
+
+    /// The aim after `commands`: the downs minus the ups.
+    fn total_before(commands: &[(usize, i32)]) -> i32 {
+        commands
+            .iter()
+            .map(|&(command, value)| match command {
+                1 => -value,
+                2 => value,
+                _ => 0,
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,226 @@
+//! Day 3: Binary Diagnostic, reading power consumption and life support ratings off the bit columns
+//! of a diagnostic report.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+use std::collections::HashSet;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// The number of `1` bits in each column of `lines`, which are all as wide as the first one.
+pub fn get_column_sums(lines: &[&str]) -> Vec<usize> {
+    let mut column_sums = vec![0; lines.first().map_or(0, |line| line.len())];
+
+    for line in lines.iter() {
+        let chars = line.chars();
+        for (i, c) in chars.enumerate() {
+            if c == '1' {
+                column_sums[i] += 1;
+            }
+        }
+    }
+
+    column_sums
+}
+
+/// Solves day 3 with the [`Solution`] trait.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AocResult<Vec<String>> {
+        let mut width = None;
+        Span::new(input)
+            .trim()
+            .lines()
+            .map(|line| {
+                let bits = line.as_str();
+                if !bits.chars().all(|c| c == '0' || c == '1') {
+                    return Err(line.error("expected a binary number").into());
+                }
+                let width = *width.get_or_insert(bits.len());
+                if bits.len() != width {
+                    return Err(line.error(format!("expected {} bits", width)).into());
+                }
+                Ok(bits.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> AocResult<u64> {
+        let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+
+        let column_sums = get_column_sums(&lines);
+        if column_sums.len() > u64::BITS as usize {
+            return Err(AocError::overflow(format!(
+                "{}-bit rates don't fit in a u64",
+                column_sums.len()
+            )));
+        }
+
+        let mut gamma: u64 = 0;
+        let mut epsilon = 0;
+        for (i, c) in column_sums.into_iter().enumerate() {
+            if i > 0 {
+                gamma <<= 1;
+                epsilon <<= 1;
+            }
+            if c > (lines.len() / 2) {
+                gamma |= 1;
+            } else {
+                epsilon |= 1;
+            }
+        }
+
+        gamma
+            .checked_mul(epsilon)
+            .ok_or_else(|| AocError::overflow("power consumption doesn't fit in a u64"))
+    }
+
+    fn part2(lines: &Vec<String>) -> AocResult<u64> {
+        let lines: Vec<&str> = lines.iter().map(|line| line.as_str()).collect();
+
+        let mut oxygen = lines.clone();
+        let mut co2 = lines.clone();
+        let bit_is = |line: &str, col: usize, bit: u8| line.as_bytes().get(col) == Some(&bit);
+
+        for col in 0..lines.first().map_or(0, |line| line.len()) {
+            let oxygen_column_sums = get_column_sums(&oxygen);
+            let co2_column_sums = get_column_sums(&co2);
+
+            if oxygen.len() > 1 {
+                if oxygen_column_sums[col] as f32 >= (oxygen.len() as f32 / 2.0) {
+                    oxygen.retain(|l| bit_is(l, col, b'1'));
+                } else {
+                    oxygen.retain(|l| bit_is(l, col, b'0'));
+                }
+            }
+
+            if co2.len() > 1 {
+                if co2_column_sums[col] as f32 >= (co2.len() as f32 / 2.0) {
+                    co2.retain(|l| bit_is(l, col, b'0'));
+                } else {
+                    co2.retain(|l| bit_is(l, col, b'1'));
+                }
+            }
+
+            if let ([oxygen], [co2]) = (&oxygen[..], &co2[..]) {
+                let rating = |bits: &str| {
+                    u64::from_str_radix(bits, 2).map_err(|_| {
+                        AocError::overflow(format!("rating {} doesn't fit in a u64", bits))
+                    })
+                };
+                return rating(oxygen)?
+                    .checked_mul(rating(co2)?)
+                    .ok_or_else(|| AocError::overflow("life support rating doesn't fit in a u64"));
+            }
+        }
+
+        Err(AocError::no_solution("no ratings found"))
+    }
+
+    /// `size` different diagnostic numbers, 12 bits wide like the real ones or as wide as it
+    /// takes to have that many.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let width = (usize::BITS - (2 * size).leading_zeros()).max(12) as usize;
+        let mut input = String::new();
+        let mut numbers = HashSet::new();
+        while numbers.len() < size {
+            let number = rng.below(1 << width);
+            if numbers.insert(number) {
+                input.push_str(&format!("{:0width$b}\n", number, width = width));
+            }
+        }
+        Some(input)
+    }
+}
+
+common::register!(3, Day03);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::hash_set;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_distinct_numbers_wide_enough() {
+        for (size, width) in [(1000, 12), (5000, 14)] {
+            let lines = Day03::parse(&DAY.generate(size, 1).unwrap()).unwrap();
+            assert!(lines.iter().all(|line| line.len() == width));
+            assert_eq!(lines.iter().collect::<HashSet<_>>().len(), size);
+        }
+    }
+
+    #[test]
+    fn solves_large_generated_inputs() {
+        let lines = Day03::parse(&DAY.generate(100_000, 1).unwrap()).unwrap();
+        assert_eq!(lines[0].len(), 18);
+        assert!(Day03::part1(&lines).unwrap() > 1 << 30);
+        assert!(Day03::part2(&lines).unwrap() > 0);
+    }
+
+    /// Diagnostic reports of distinct numbers, all `width` bits wide.
+    fn report() -> impl Strategy<Value = Vec<String>> {
+        (1usize..12).prop_flat_map(|width| {
+            hash_set(0u32..1 << width, 1..=(1 << width).min(20)).prop_map(move |numbers| {
+                numbers
+                    .into_iter()
+                    .map(|n| format!("{:0width$b}", n, width = width))
+                    .collect()
+            })
+        })
+    }
+
+    fn ones(lines: &[String], col: usize) -> usize {
+        lines
+            .iter()
+            .filter(|line| line.as_bytes()[col] == b'1')
+            .count()
+    }
+
+    /// Keeps the lines with the bit the criteria picks in each column until one line is left, or
+    /// none when every line has the bit the criteria rejects.
+    fn rating(lines: &[String], keep_most_common: bool) -> Option<u64> {
+        let mut lines = lines.to_vec();
+        let mut col = 0;
+        while lines.len() > 1 {
+            let ones_win = ones(&lines, col) * 2 >= lines.len();
+            let keep = if ones_win == keep_most_common {
+                b'1'
+            } else {
+                b'0'
+            };
+            lines.retain(|line| line.as_bytes()[col] == keep);
+            col += 1;
+        }
+        Some(u64::from_str_radix(lines.first()?, 2).unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_counting_each_column(lines in report()) {
+            let parsed = Day03::parse(&lines.join("\n")).unwrap();
+            let width = lines[0].len();
+            let gamma = (0..width).fold(0, |gamma, col| {
+                (gamma << 1) | (ones(&lines, col) * 2 > lines.len()) as u64
+            });
+            let epsilon = !gamma & ((1 << width) - 1);
+
+            prop_assert_eq!(Day03::part1(&parsed).unwrap(), gamma * epsilon);
+            match (rating(&lines, true), rating(&lines, false)) {
+                (Some(oxygen), Some(co2)) => {
+                    prop_assert_eq!(Day03::part2(&parsed).unwrap(), oxygen * co2)
+                }
+                _ => prop_assert!(Day03::part2(&parsed).is_err()),
+            }
+        }
+    }
+}
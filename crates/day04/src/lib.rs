@@ -0,0 +1,306 @@
+//! Day 4: Giant Squid, playing [`Bingo`] against a giant squid, to find the first and the last
+//! [`Board`] to win.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+use std::collections::HashMap;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+#[derive(Debug, Clone, Copy)]
+enum BoardNum {
+    Marked,
+    Unmarked(u8),
+}
+
+/// A 5 by 5 bingo board that keeps track of which of its numbers were drawn.
+#[derive(Debug, Clone)]
+pub struct Board {
+    board: [[BoardNum; 5]; 5],
+    marked_cols: HashMap<usize, u8>,
+    marked_rows: HashMap<usize, u8>,
+}
+
+impl Board {
+    /// Parses five lines of five whitespace separated numbers.
+    pub fn parse(span: Span) -> AocResult<Self> {
+        let mut board = [[BoardNum::Unmarked(0); 5]; 5];
+
+        let mut rows = 0;
+        for (y, line) in span.lines().enumerate() {
+            let mut columns = 0;
+            for (x, num) in line.words().enumerate() {
+                if x >= 5 || y >= 5 {
+                    return Err(num.error("boards are 5 by 5 numbers").into());
+                }
+                board[y][x] = BoardNum::Unmarked(num.parse()?);
+                columns += 1;
+            }
+            if columns < 5 {
+                return Err(line
+                    .error(format!("row has {} numbers, expected 5", columns))
+                    .into());
+            }
+            rows += 1;
+        }
+        if rows < 5 {
+            return Err(span
+                .error(format!("board has {} rows, expected 5", rows))
+                .into());
+        }
+
+        Ok(Self {
+            board,
+            marked_cols: HashMap::new(),
+            marked_rows: HashMap::new(),
+        })
+    }
+
+    /// Marks `drawn_num` if the board has it.
+    pub fn draw(&mut self, drawn_num: u8) {
+        for row in 0..5 {
+            for col in 0..5 {
+                if let BoardNum::Unmarked(num) = self.board[row][col] {
+                    if num == drawn_num {
+                        self.board[row][col] = BoardNum::Marked;
+                        let row_count = self.marked_rows.entry(row).or_insert(0);
+                        *row_count += 1;
+                        let col_count = self.marked_cols.entry(col).or_insert(0);
+                        *col_count += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether a whole row or column is marked.
+    pub fn is_win(&self) -> bool {
+        if self.marked_rows.values().any(|&count| count == 5)
+            || self.marked_cols.values().any(|&count| count == 5)
+        {
+            return true;
+        }
+        false
+    }
+
+    /// The sum of the numbers that aren't marked.
+    pub fn score(&self) -> u32 {
+        let mut score = 0;
+        for row in 0..5 {
+            for col in 0..5 {
+                if let BoardNum::Unmarked(num) = self.board[row][col] {
+                    score += num as u32;
+                }
+            }
+        }
+        score
+    }
+}
+
+/// The numbers drawn, in order, and the boards playing.
+pub struct Bingo {
+    draws: Vec<u8>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    pub fn draws(&self) -> &[u8] {
+        &self.draws
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+}
+
+/// Solves day 4 with the [`Solution`] trait.
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AocResult<Bingo> {
+        let span = Span::new(input);
+        let mut sections = span.sections();
+
+        let draws = sections
+            .next()
+            .ok_or_else(|| span.error("missing draws"))?
+            .comma_ints()?;
+
+        let mut boards: Vec<Board> = vec![];
+
+        for section in sections {
+            boards.push(Board::parse(section)?);
+        }
+
+        Ok(Bingo { draws, boards })
+    }
+
+    fn part1(bingo: &Bingo) -> AocResult<u32> {
+        let mut boards = bingo.boards.clone();
+
+        for &draw in &bingo.draws {
+            for board in &mut boards {
+                board.draw(draw);
+                if board.is_win() {
+                    return Ok(board.score() * draw as u32);
+                }
+            }
+        }
+
+        Err(AocError::no_solution("no board ever wins"))
+    }
+
+    fn part2(bingo: &Bingo) -> AocResult<u32> {
+        let mut boards = bingo.boards.clone();
+
+        for &draw in &bingo.draws {
+            if let [board] = &mut boards[..] {
+                board.draw(draw);
+                if board.is_win() {
+                    return Ok(board.score() * draw as u32);
+                }
+            } else {
+                for board in &mut boards {
+                    board.draw(draw);
+                }
+                boards.retain(|board| !board.is_win());
+            }
+        }
+
+        Err(AocError::no_solution("the last board never wins"))
+    }
+
+    /// Every number from 0 to 99 drawn in a random order, and `size` boards.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let mut numbers: Vec<u8> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        let draws: Vec<String> = numbers.iter().map(u8::to_string).collect();
+        let mut input = format!("{}\n", draws.join(","));
+        for _ in 0..size.max(1) {
+            rng.shuffle(&mut numbers);
+            input.push('\n');
+            for row in numbers[..25].chunks(5) {
+                let row: Vec<String> = row.iter().map(|num| format!("{:2}", num)).collect();
+                input.push_str(&format!("{}\n", row.join(" ")));
+            }
+        }
+        Some(input)
+    }
+}
+
+common::register!(4, Day04);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    common::example_tests!();
+
+    #[test]
+    fn rejects_short_boards() {
+        let row = "1 2 3 4 5\n";
+        let short_row = format!("7\n\n{}{}1 2 3 4\n{}{}", row, row, row, row);
+        let Err(err) = Day04::parse(&short_row) else {
+            panic!("a row of 4 numbers was accepted");
+        };
+        assert!(
+            matches!(err, AocError::MalformedInput(err) if err.line == 5 && err.text == "1 2 3 4")
+        );
+
+        let short_board = format!("7\n\n{}{}{}{}", row, row, row, row);
+        let Err(err) = Day04::parse(&short_board) else {
+            panic!("a board of 4 rows was accepted");
+        };
+        assert!(matches!(err, AocError::MalformedInput(err) if err.line == 3));
+    }
+
+    #[test]
+    fn generates_games_every_board_finishes() {
+        let bingo = Day04::parse(&DAY.generate(100, 1).unwrap()).unwrap();
+        assert_eq!(bingo.boards().len(), 100);
+        let mut draws = bingo.draws().to_vec();
+        draws.sort_unstable();
+        assert_eq!(draws, (0..100).collect::<Vec<u8>>());
+        // Every number is drawn, so every board wins eventually.
+        Day04::part2(&bingo).unwrap();
+    }
+
+    fn numbers() -> impl Strategy<Value = Vec<u8>> {
+        Just((0..50).collect::<Vec<u8>>()).prop_shuffle()
+    }
+
+    /// How many draws it takes `board` to win, checking every row and column after each draw.
+    fn draws_to_win(board: &[u8], draws: &[u8]) -> Option<usize> {
+        let mut drawn = HashSet::new();
+        for (turn, draw) in draws.iter().enumerate() {
+            drawn.insert(*draw);
+            let marked = |i: usize| drawn.contains(&board[i]);
+            let row = (0..5).any(|y| (0..5).all(|x| marked(y * 5 + x)));
+            let column = (0..5).any(|x| (0..5).all(|y| marked(y * 5 + x)));
+            if row || column {
+                return Some(turn + 1);
+            }
+        }
+        None
+    }
+
+    fn score(board: &[u8], draws: &[u8]) -> u32 {
+        let drawn: HashSet<_> = draws.iter().collect();
+        let unmarked: u32 = board
+            .iter()
+            .filter(|n| !drawn.contains(n))
+            .map(|&n| n as u32)
+            .sum();
+        unmarked * *draws.last().unwrap() as u32
+    }
+
+    proptest! {
+        #[test]
+        fn matches_checking_every_board_after_every_draw(
+            draws in numbers(),
+            boards in vec(numbers(), 1..5),
+        ) {
+            let boards: Vec<&[u8]> = boards.iter().map(|numbers| &numbers[..25]).collect();
+            let mut text = draws.iter().map(u8::to_string).collect::<Vec<_>>().join(",");
+            for board in &boards {
+                text.push('\n');
+                for row in board.chunks(5) {
+                    text.push('\n');
+                    text.push_str(&row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>().join(" "));
+                }
+            }
+            let bingo = Day04::parse(&text).unwrap();
+
+            // Every number is drawn, so every board wins eventually.
+            let turns: Vec<usize> = boards
+                .iter()
+                .map(|board| draws_to_win(board, &draws).unwrap())
+                .collect();
+            let first = (0..boards.len()).min_by_key(|&i| turns[i]).unwrap();
+            prop_assert_eq!(
+                Day04::part1(&bingo).unwrap(),
+                score(boards[first], &draws[..turns[first]])
+            );
+
+            let last_turn = *turns.iter().max().unwrap();
+            let last: Vec<usize> = (0..boards.len()).filter(|&i| turns[i] == last_turn).collect();
+            match &last[..] {
+                [last] => prop_assert_eq!(
+                    Day04::part2(&bingo).unwrap(),
+                    score(boards[*last], &draws[..last_turn])
+                ),
+                // Boards that win together leave no single last board.
+                _ => prop_assert!(Day04::part2(&bingo).is_err()),
+            }
+        }
+    }
+}
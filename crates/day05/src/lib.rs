@@ -0,0 +1,204 @@
+//! Day 5: Hydrothermal Venture, counting the points where lines of hydrothermal vents overlap.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocResult, Point, Solution};
+use std::collections::HashMap;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// Prints how many vents cover each point as a map, with `.` where there are none.
+pub fn print_ocean_floor(covered_points: &HashMap<Point, i32>) {
+    let max_x = covered_points.keys().map(|p| p.x).max().unwrap_or(-1);
+    let max_y = covered_points.keys().map(|p| p.y).max().unwrap_or(-1);
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            let overlap = covered_points.get(&Point { x, y });
+            if let Some(overlap) = overlap {
+                print!("{}", overlap);
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+/// Parses one `x1,y1 -> x2,y2` vent per line into its start and end points. Vents must be
+/// horizontal, vertical or at 45 degrees.
+pub fn parse_vents(input: &str) -> AocResult<Vec<(Point, Point)>> {
+    Span::new(input)
+        .trim()
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once(" -> ")?;
+            let (start, end) = (start.point()?, end.point()?);
+            let d = end - start;
+            if d.x != 0 && d.y != 0 && d.x.abs() != d.y.abs() {
+                return Err(line
+                    .error("vents must be horizontal, vertical or at 45 degrees")
+                    .into());
+            }
+            Ok((start, end))
+        })
+        .collect()
+}
+
+/// Counts how many vents cover each point. Vents are horizontal, vertical or at 45 degrees.
+pub fn get_covered_points(vents: &[(Point, Point)]) -> HashMap<Point, i32> {
+    let mut covered_points: HashMap<Point, i32> = HashMap::new();
+
+    for &(start, end) in vents.iter() {
+        let step = (end - start).signum();
+        let mut point = start;
+        loop {
+            *covered_points.entry(point).or_insert(0) += 1;
+            if point == end {
+                break;
+            }
+            point += step;
+        }
+    }
+
+    covered_points
+}
+
+/// Solves day 5 with the [`Solution`] trait.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<(Point, Point)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Vec<(Point, Point)>> {
+        parse_vents(input)
+    }
+
+    fn part1(vents: &Vec<(Point, Point)>) -> AocResult<usize> {
+        let vents: Vec<(Point, Point)> = vents
+            .iter()
+            .filter(|(start, end)| start.x == end.x || start.y == end.y)
+            .copied()
+            .collect();
+        let covered_points = get_covered_points(&vents);
+
+        Ok(covered_points
+            .into_values()
+            .filter(|&overlap| overlap >= 2)
+            .count())
+    }
+
+    fn part2(vents: &Vec<(Point, Point)>) -> AocResult<usize> {
+        let covered_points = get_covered_points(vents);
+
+        Ok(covered_points
+            .into_values()
+            .filter(|&overlap| overlap >= 2)
+            .count())
+    }
+
+    /// `size` vents on a 1000 by 1000 floor, a quarter of them diagonal.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        const SIDE: usize = 1000;
+        let mut input = String::new();
+        for _ in 0..size {
+            let (x, y) = (rng.below(SIDE), rng.below(SIDE));
+            let (end_x, end_y) = match rng.below(8) {
+                0..=2 => (rng.below(SIDE), y),
+                3..=5 => (x, rng.below(SIDE)),
+                _ => {
+                    let (right, down) = (rng.chance(0.5), rng.chance(0.5));
+                    let room_x = if right { SIDE - 1 - x } else { x };
+                    let room_y = if down { SIDE - 1 - y } else { y };
+                    let length = rng.below(room_x.min(room_y) + 1);
+                    (
+                        if right { x + length } else { x - length },
+                        if down { y + length } else { y - length },
+                    )
+                }
+            };
+            input.push_str(&format!("{},{} -> {},{}\n", x, y, end_x, end_y));
+        }
+        Some(input)
+    }
+}
+
+common::register!(5, Day05);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::AocError;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_vents_on_the_floor() {
+        let vents = Day05::parse(&DAY.generate(200, 1).unwrap()).unwrap();
+        assert_eq!(vents.len(), 200);
+        let on_floor = |point: Point| (0..1000).contains(&point.x) && (0..1000).contains(&point.y);
+        assert!(vents
+            .iter()
+            .all(|&(start, end)| on_floor(start) && on_floor(end)));
+        let diagonal = vents
+            .iter()
+            .filter(|(start, end)| start.x != end.x && start.y != end.y)
+            .count();
+        assert!((25..75).contains(&diagonal));
+    }
+
+    #[test]
+    fn rejects_vents_at_other_angles() {
+        let err = Day05::parse("0,9 -> 5,9\n0,0 -> 2,1\n").unwrap_err();
+        assert!(matches!(err, AocError::MalformedInput(err) if err.line == 2 && err.column == 1));
+    }
+
+    /// A vent from a point in a 10 by 10 area, going up to 5 steps in one of the 8 directions.
+    fn vent() -> impl Strategy<Value = (Point, Point)> {
+        (0..10, 0..10, 0..8usize, 0..6).prop_map(|(x, y, direction, length)| {
+            let start = Point::new(x, y);
+            (start, start + Point::DIRECTIONS8[direction] * length)
+        })
+    }
+
+    fn covers(&(start, end): &(Point, Point), point: Point) -> bool {
+        let (a, b) = (end - start, point - start);
+        let on_line = a.x * b.y == a.y * b.x;
+        let in_box = (start.x.min(end.x)..=start.x.max(end.x)).contains(&point.x)
+            && (start.y.min(end.y)..=start.y.max(end.y)).contains(&point.y);
+        on_line && in_box
+    }
+
+    /// Counts the points covered by at least two vents by trying every point near the vents.
+    fn overlaps(vents: &[(Point, Point)]) -> usize {
+        (-5..15)
+            .flat_map(|y| (-5..15).map(move |x| Point::new(x, y)))
+            .filter(|&point| vents.iter().filter(|vent| covers(vent, point)).count() >= 2)
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_checking_every_point(vents in vec(vent(), 0..20)) {
+            let text = vents
+                .iter()
+                .map(|(start, end)| format!("{} -> {}", start, end))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let parsed = Day05::parse(&text).unwrap();
+            let straight: Vec<_> = vents
+                .iter()
+                .copied()
+                .filter(|(start, end)| start.x == end.x || start.y == end.y)
+                .collect();
+
+            prop_assert_eq!(Day05::part1(&parsed).unwrap(), overlaps(&straight));
+            prop_assert_eq!(Day05::part2(&parsed).unwrap(), overlaps(&vents));
+        }
+    }
+}
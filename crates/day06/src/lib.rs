@@ -0,0 +1,130 @@
+//! Day 6: Lanternfish, simulating a school of lanternfish whose population doubles every week.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// Advances the timer of every fish by a day, adding a new fish for every one that reaches 0.
+///
+/// This keeps one entry per fish, so it is only practical for the first few weeks.
+pub fn simulate_day(fish: &mut Vec<u8>) {
+    for i in 0..fish.len() {
+        if fish[i] == 0 {
+            fish[i] = 6;
+            fish.push(8)
+        } else {
+            fish[i] -= 1;
+        }
+    }
+}
+
+/// Like [`simulate_day`], but with the number of fish for each timer value instead of one entry
+/// per fish.
+pub fn simulate_day_with_map(fish_map: &mut [u64; 9]) -> AocResult<()> {
+    fish_map.rotate_left(1);
+    fish_map[6] = fish_map[6]
+        .checked_add(fish_map[8])
+        .ok_or_else(|| AocError::overflow("lanternfish count doesn't fit in a u64"))?;
+    Ok(())
+}
+
+/// Solves day 6 with the [`Solution`] trait.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u8>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AocResult<Vec<u8>> {
+        Span::new(input)
+            .trim()
+            .split(",")
+            .map(|timer| match timer.parse()? {
+                timer @ 0..=8 => Ok(timer),
+                _ => Err(timer.error("timers go from 0 to 8").into()),
+            })
+            .collect()
+    }
+
+    fn part1(fish: &Vec<u8>) -> AocResult<usize> {
+        let mut fish = fish.clone();
+
+        for _ in 0..80 {
+            simulate_day(&mut fish);
+        }
+
+        Ok(fish.len())
+    }
+
+    fn part2(fish: &Vec<u8>) -> AocResult<u64> {
+        let mut fish_map = [0; 9];
+
+        for &f in fish {
+            fish_map[f as usize] += 1;
+        }
+
+        for _ in 0..256 {
+            simulate_day_with_map(&mut fish_map)?;
+        }
+
+        fish_map
+            .iter()
+            .try_fold(0u64, |total, &count| total.checked_add(count))
+            .ok_or_else(|| AocError::overflow("lanternfish count doesn't fit in a u64"))
+    }
+
+    /// `size` lanternfish with timers from 1 to 5, like the real ones.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let timers: Vec<String> = (0..size.max(1))
+            .map(|_| rng.between(1, 5).to_string())
+            .collect();
+        Some(format!("{}\n", timers.join(",")))
+    }
+}
+
+common::register!(6, Day06);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_timers_like_the_real_ones() {
+        let fish = Day06::parse(&DAY.generate(50, 1).unwrap()).unwrap();
+        assert_eq!(fish.len(), 50);
+        assert!(fish.iter().all(|timer| (1..=5).contains(timer)));
+    }
+
+    proptest! {
+        #[test]
+        fn map_simulation_matches_simulating_every_fish(
+            fish in vec(0u8..=8, 1..20),
+            days in 0usize..60,
+        ) {
+            let text = fish.iter().map(u8::to_string).collect::<Vec<_>>().join(",");
+            let mut fish = Day06::parse(&text).unwrap();
+            let mut fish_map = [0; 9];
+            for &f in &fish {
+                fish_map[f as usize] += 1;
+            }
+
+            for _ in 0..days {
+                simulate_day(&mut fish);
+                simulate_day_with_map(&mut fish_map).unwrap();
+            }
+
+            for (timer, &count) in fish_map.iter().enumerate() {
+                let expected = fish.iter().filter(|&&f| f as usize == timer).count();
+                prop_assert_eq!(count, expected as u64, "fish with timer {}", timer);
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 7: The Treachery of Whales, finding the position crab submarines can line up at for the
+//! least fuel.
+
+use common::generate::Rng;
+use common::parse::{ParseError, Span};
+use common::{AocError, AocResult, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// Parses the comma separated horizontal positions of the crabs.
+pub fn parse_position(input: &str) -> Result<Vec<u32>, ParseError> {
+    Span::new(input).trim().comma_ints()
+}
+
+/// The least fuel needed to align every crab on the same position. `fuel_fn` gives the fuel one
+/// crab needs to move to a position, or `None` if it doesn't fit in a `u32`.
+pub fn find_best_fuel(positions: &[u32], fuel_fn: fn(u32, u32) -> Option<u32>) -> AocResult<u32> {
+    let (Some(&min), Some(&max)) = (positions.iter().min(), positions.iter().max()) else {
+        return Err(AocError::no_solution("there are no crabs to align"));
+    };
+
+    let mut best_fuel = None;
+
+    for center in min..=max {
+        let fuel = positions
+            .iter()
+            .try_fold(0u32, |total, &p| total.checked_add(fuel_fn(p, center)?));
+        // A position whose fuel overflows can't be the best one unless they all overflow.
+        let Some(fuel) = fuel else {
+            continue;
+        };
+
+        if let Some(best) = best_fuel {
+            if fuel < best {
+                best_fuel = Some(fuel);
+            }
+        } else {
+            best_fuel = Some(fuel);
+        }
+    }
+
+    best_fuel.ok_or_else(|| AocError::overflow("the fuel needed doesn't fit in a u32"))
+}
+
+/// Fuel for crabs that burn one unit per step.
+pub fn linear_fuel(position: u32, center: u32) -> Option<u32> {
+    Some(center.abs_diff(position))
+}
+
+/// Fuel for crabs that burn one more unit with every step than with the one before.
+pub fn summation_fuel(position: u32, center: u32) -> Option<u32> {
+    let fuel = center.abs_diff(position);
+    Some(fuel.checked_mul(fuel.checked_add(1)?)? / 2)
+}
+
+/// Solves day 7 with the [`Solution`] trait.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AocResult<Vec<u32>> {
+        Ok(parse_position(input)?)
+    }
+
+    fn part1(positions: &Vec<u32>) -> AocResult<u32> {
+        find_best_fuel(positions, linear_fuel)
+    }
+
+    fn part2(positions: &Vec<u32>) -> AocResult<u32> {
+        find_best_fuel(positions, summation_fuel)
+    }
+
+    /// `size` crabs below 2000, most of them in the lower positions like the real ones.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let positions: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let spread = rng.between(1, 2000);
+                rng.below(spread).to_string()
+            })
+            .collect();
+        Some(format!("{}\n", positions.join(",")))
+    }
+}
+
+common::register!(7, Day07);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_crabs_mostly_in_low_positions() {
+        let mut positions = Day07::parse(&DAY.generate(500, 1).unwrap()).unwrap();
+        assert_eq!(positions.len(), 500);
+        positions.sort_unstable();
+        assert!(positions[499] < 2000);
+        assert!(positions[250] < 1000);
+    }
+
+    #[test]
+    fn reports_fuel_overflow() {
+        let positions = Day07::parse("0,200000").unwrap();
+        assert_eq!(Day07::part1(&positions).unwrap(), 200000);
+        assert!(matches!(
+            Day07::part2(&positions),
+            Err(AocError::Overflow(_))
+        ));
+        assert!(matches!(
+            find_best_fuel(&[], linear_fuel),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+
+    /// Tries every position up to the furthest crab, spelling the fuel out step by step.
+    fn brute_force_fuel(positions: &[u32], steps_cost_more: bool) -> u64 {
+        let furthest = *positions.iter().max().unwrap();
+        (0..=furthest)
+            .map(|center| {
+                positions
+                    .iter()
+                    .map(|&position| {
+                        let distance = position.abs_diff(center) as u64;
+                        if steps_cost_more {
+                            (1..=distance).sum()
+                        } else {
+                            distance
+                        }
+                    })
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn finds_the_least_fuel(positions in vec(0u32..200, 1..30)) {
+            let text = positions.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+            let positions = Day07::parse(&text).unwrap();
+            prop_assert_eq!(
+                Day07::part1(&positions).unwrap() as u64,
+                brute_force_fuel(&positions, false)
+            );
+            prop_assert_eq!(
+                Day07::part2(&positions).unwrap() as u64,
+                brute_force_fuel(&positions, true)
+            );
+        }
+    }
+}
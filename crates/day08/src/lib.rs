@@ -0,0 +1,301 @@
+//! Day 8: Seven Segment Search, decoding seven segment displays whose wires got mixed up.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+use std::collections::HashSet;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// One display: the ten unique signal patterns seen on its wires, and the four digits of its
+/// output, each pattern a set of the segments `a` to `g`.
+pub struct Entry {
+    signal_patterns: Vec<String>,
+    output: Vec<String>,
+}
+
+impl Entry {
+    pub fn signal_patterns(&self) -> &[String] {
+        &self.signal_patterns
+    }
+
+    pub fn output(&self) -> &[String] {
+        &self.output
+    }
+}
+
+/// Solves day 8 with the [`Solution`] trait.
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+    type Part1 = i32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AocResult<Vec<Entry>> {
+        Span::new(input)
+            .trim()
+            .lines()
+            .map(|line| {
+                let (signal_patterns, output) = line.split_once(" | ")?;
+                Ok(Entry {
+                    signal_patterns: signal_patterns
+                        .words()
+                        .map(|s| s.as_str().to_string())
+                        .collect(),
+                    output: output.words().map(|s| s.as_str().to_string()).collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(entries: &Vec<Entry>) -> AocResult<i32> {
+        let mut digit_count = 0;
+        for entry in entries {
+            for digit in &entry.output {
+                let mut chars = HashSet::new();
+                for char in digit.chars() {
+                    chars.insert(char);
+                }
+
+                if chars.len() == 2 || chars.len() == 3 || chars.len() == 4 || chars.len() == 7 {
+                    digit_count += 1;
+                }
+            }
+        }
+
+        Ok(digit_count)
+    }
+
+    fn part2(entries: &Vec<Entry>) -> AocResult<u32> {
+        let mut output_nums = Vec::new();
+
+        for entry in entries {
+            let mut signal_patterns: Vec<&str> =
+                entry.signal_patterns.iter().map(|s| s.as_str()).collect();
+
+            let mut one = HashSet::new();
+            let mut seven = HashSet::new();
+            let mut four = HashSet::new();
+            let mut eight = HashSet::new();
+            signal_patterns.retain(|digit| {
+                if digit.len() == 2 {
+                    for char in digit.chars() {
+                        one.insert(char);
+                    }
+                    return false;
+                } else if digit.len() == 3 {
+                    for char in digit.chars() {
+                        seven.insert(char);
+                    }
+                    return false;
+                } else if digit.len() == 4 {
+                    for char in digit.chars() {
+                        four.insert(char);
+                    }
+                    return false;
+                } else if digit.len() == 7 {
+                    for char in digit.chars() {
+                        eight.insert(char);
+                    }
+                    return false;
+                }
+                true
+            });
+
+            let mut zero = HashSet::new();
+            let mut six = HashSet::new();
+            let mut nine = HashSet::new();
+            signal_patterns.retain(|digit| {
+                if digit.len() == 6 {
+                    let mut chars = HashSet::new();
+                    for char in digit.chars() {
+                        chars.insert(char);
+                    }
+
+                    if chars.is_superset(&four) {
+                        nine = chars;
+                    } else if chars.is_superset(&one) {
+                        zero = chars;
+                    } else {
+                        six = chars;
+                    }
+                    return false;
+                }
+                true
+            });
+
+            let unwired = || AocError::no_solution("the signal patterns don't match the digits");
+            let top_right = eight.difference(&six).next().ok_or_else(unwired)?;
+            let bottom_left = eight.difference(&nine).next().ok_or_else(unwired)?;
+
+            let mut two = HashSet::new();
+            let mut three = HashSet::new();
+            let mut five = HashSet::new();
+            for digit in signal_patterns {
+                let mut chars = HashSet::new();
+                for char in digit.chars() {
+                    chars.insert(char);
+                }
+
+                if !chars.contains(top_right) && !chars.contains(bottom_left) {
+                    five = chars;
+                } else if !chars.contains(bottom_left) {
+                    three = chars;
+                } else {
+                    two = chars;
+                }
+            }
+
+            let mut output_num: u32 = 0;
+            for digit in &entry.output {
+                let mut chars = HashSet::new();
+                for char in digit.chars() {
+                    chars.insert(char);
+                }
+
+                let value = if chars == zero {
+                    0
+                } else if chars == one {
+                    1
+                } else if chars == two {
+                    2
+                } else if chars == three {
+                    3
+                } else if chars == four {
+                    4
+                } else if chars == five {
+                    5
+                } else if chars == six {
+                    6
+                } else if chars == seven {
+                    7
+                } else if chars == eight {
+                    8
+                } else if chars == nine {
+                    9
+                } else {
+                    return Err(AocError::no_solution(format!(
+                        "output digit {} matches no signal pattern",
+                        digit
+                    )));
+                };
+                output_num = output_num
+                    .checked_mul(10)
+                    .and_then(|num| num.checked_add(value))
+                    .ok_or_else(|| AocError::overflow("output value doesn't fit in a u32"))?;
+            }
+            output_nums.push(output_num);
+        }
+
+        output_nums
+            .into_iter()
+            .try_fold(0u32, |total, num| total.checked_add(num))
+            .ok_or_else(|| AocError::overflow("sum of output values doesn't fit in a u32"))
+    }
+
+    /// `size` displays, each with its own random wiring and four random output digits.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        const DIGITS: [&str; 10] = [
+            "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+        ];
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut wires: Vec<char> = ('a'..='g').collect();
+            rng.shuffle(&mut wires);
+            let wire = |digit: &str, rng: &mut Rng| {
+                let mut pattern: Vec<char> = digit
+                    .bytes()
+                    .map(|segment| wires[usize::from(segment - b'a')])
+                    .collect();
+                rng.shuffle(&mut pattern);
+                pattern.into_iter().collect::<String>()
+            };
+            let mut patterns: Vec<String> = DIGITS.iter().map(|digit| wire(digit, rng)).collect();
+            let output: Vec<String> = (0..4)
+                .map(|_| {
+                    let digit = DIGITS[rng.below(10)];
+                    wire(digit, rng)
+                })
+                .collect();
+            rng.shuffle(&mut patterns);
+            input.push_str(&format!("{} | {}\n", patterns.join(" "), output.join(" ")));
+        }
+        Some(input)
+    }
+}
+
+common::register!(8, Day08);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_every_digit_once_per_display() {
+        let entries = Day08::parse(&DAY.generate(200, 1).unwrap()).unwrap();
+        assert_eq!(entries.len(), 200);
+        for entry in &entries {
+            let mut lengths: Vec<usize> = entry.signal_patterns().iter().map(String::len).collect();
+            lengths.sort_unstable();
+            assert_eq!(lengths, [2, 3, 4, 5, 5, 5, 6, 6, 6, 7]);
+        }
+        // Every wiring is consistent, so every display decodes.
+        Day08::part2(&entries).unwrap();
+    }
+
+    const SEGMENTS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    /// A note entry with the segments wired by `wiring`, listing the digits in the order of
+    /// `patterns`, and the four output digits.
+    fn entry(wiring: &[char], patterns: &[usize], output: &[usize]) -> String {
+        let wire = |digit: &usize| -> String {
+            SEGMENTS[*digit]
+                .chars()
+                .map(|segment| wiring[(segment as u8 - b'a') as usize])
+                .collect()
+        };
+        let patterns: Vec<String> = patterns.iter().map(wire).collect();
+        let output: Vec<String> = output.iter().map(wire).collect();
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }
+
+    fn scrambled_entry() -> impl Strategy<Value = (Vec<char>, Vec<usize>, Vec<usize>)> {
+        (
+            Just(('a'..='g').collect::<Vec<_>>()).prop_shuffle(),
+            Just((0..10).collect::<Vec<_>>()).prop_shuffle(),
+            vec(0..10usize, 4),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn decodes_any_wiring(entries in vec(scrambled_entry(), 1..5)) {
+            let text = entries
+                .iter()
+                .map(|(wiring, patterns, output)| entry(wiring, patterns, output))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let parsed = Day08::parse(&text).unwrap();
+            let easy_digits = entries
+                .iter()
+                .flat_map(|(_, _, output)| output)
+                .filter(|digit| [1, 4, 7, 8].contains(*digit))
+                .count();
+            let sum: usize = entries
+                .iter()
+                .map(|(_, _, output)| output.iter().fold(0, |n, digit| n * 10 + digit))
+                .sum();
+
+            prop_assert_eq!(Day08::part1(&parsed).unwrap() as usize, easy_digits);
+            prop_assert_eq!(Day08::part2(&parsed).unwrap() as usize, sum);
+        }
+    }
+}
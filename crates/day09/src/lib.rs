@@ -0,0 +1,239 @@
+//! Day 9: Smoke Basin, finding the low points of a height map and the basins that flow into them.
+
+use common::generate::Rng;
+use common::{search, AocError, AocResult, Grid, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// A point lower than all of its orthogonal neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowPoint {
+    pub x: usize,
+    pub y: usize,
+    pub height: u32,
+}
+
+/// Every low point of the height map, in row order.
+pub fn get_low_points(grid: &Grid<u32>) -> Vec<LowPoint> {
+    let mut low_points = Vec::new();
+    for (x, y) in grid.positions() {
+        let current = grid[(x, y)];
+        if grid
+            .neighbors4(x, y)
+            .all(|neighbor| grid[neighbor] > current)
+        {
+            low_points.push(LowPoint {
+                x,
+                y,
+                height: current,
+            });
+        }
+    }
+    low_points
+}
+
+/// The number of positions that flow down to the low point at `(x, y)`, which is every position
+/// reachable from it without crossing a height of 9.
+pub fn get_basin_size(grid: &Grid<u32>, x: usize, y: usize) -> u32 {
+    let basin = search::reachable((x, y), |&(x, y)| {
+        grid.neighbors4(x, y)
+            .filter(|&neighbor| grid[neighbor] != 9)
+    });
+
+    basin.len() as u32
+}
+
+/// Solves day 9 with the [`Solution`] trait.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> AocResult<Grid<u32>> {
+        Ok(Grid::parse_digits(input)?)
+    }
+
+    fn part1(grid: &Grid<u32>) -> AocResult<u32> {
+        let low_points = get_low_points(grid);
+
+        low_points
+            .into_iter()
+            .try_fold(0u32, |total, p| total.checked_add(p.height + 1))
+            .ok_or_else(|| AocError::overflow("sum of risk levels doesn't fit in a u32"))
+    }
+
+    fn part2(grid: &Grid<u32>) -> AocResult<u32> {
+        let mut basin_sizes = vec![];
+        for low_point in get_low_points(grid) {
+            basin_sizes.push(get_basin_size(grid, low_point.x, low_point.y));
+        }
+
+        basin_sizes.sort_unstable();
+        basin_sizes
+            .into_iter()
+            .rev()
+            .take(3)
+            .try_fold(1u32, |product, size| product.checked_mul(size))
+            .ok_or_else(|| AocError::overflow("product of basin sizes doesn't fit in a u32"))
+    }
+
+    /// A `size` by `size` height map of rectangular basins up to 10 by 10, walled in by 9s and
+    /// each sloping up from a single low point.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        // The rows or columns between two walls, as ranges.
+        let basins = |rng: &mut Rng| {
+            let mut basins = vec![];
+            let mut start = 0;
+            for i in 0..size {
+                let width = i - start;
+                if width > 0 && (width == 10 || rng.chance(0.15)) {
+                    basins.push(start..i);
+                    start = i + 1;
+                }
+            }
+            if start < size {
+                basins.push(start..size);
+            }
+            basins
+        };
+        let (rows, columns) = (basins(rng), basins(rng));
+
+        let mut heights = vec![vec![9; size]; size];
+        for row in &rows {
+            for column in &columns {
+                let low_x = rng.between(column.start, column.end - 1);
+                let low_y = rng.between(row.start, row.end - 1);
+                for y in row.clone() {
+                    for x in column.clone() {
+                        heights[y][x] = (low_x.abs_diff(x) + low_y.abs_diff(y)).min(8);
+                    }
+                }
+            }
+        }
+
+        let mut input = String::new();
+        for row in heights {
+            input.extend(
+                row.into_iter()
+                    .map(|height| char::from(b'0' + height as u8)),
+            );
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+common::register!(9, Day09);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_basins_with_one_low_point() {
+        let grid = Day09::parse(&DAY.generate(100, 1).unwrap()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (100, 100));
+        for low_point in get_low_points(&grid) {
+            assert_eq!(low_point.height, 0);
+            assert!(get_basin_size(&grid, low_point.x, low_point.y) <= 100);
+        }
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let basin = format!("0{}", "1".repeat(1999));
+        let grid = Day09::parse(&[basin.as_str(); 3].join("9")).unwrap();
+        assert_eq!(Day09::part1(&grid).unwrap(), 3);
+        assert!(matches!(Day09::part2(&grid), Err(AocError::Overflow(_))));
+    }
+
+    fn heightmap() -> impl Strategy<Value = Vec<Vec<u32>>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| vec(vec(0..=9u32, width), height))
+    }
+
+    fn neighbors(rows: &[Vec<u32>], x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut neighbors = vec![];
+        if x > 0 {
+            neighbors.push((x - 1, y));
+        }
+        if y > 0 {
+            neighbors.push((x, y - 1));
+        }
+        if x + 1 < rows[0].len() {
+            neighbors.push((x + 1, y));
+        }
+        if y + 1 < rows.len() {
+            neighbors.push((x, y + 1));
+        }
+        neighbors
+    }
+
+    /// Labels every cell that isn't a 9 with the smallest index in its basin, by spreading
+    /// labels between neighbors until nothing changes.
+    fn basin_labels(rows: &[Vec<u32>]) -> Vec<Vec<Option<usize>>> {
+        let width = rows[0].len();
+        let mut labels: Vec<Vec<Option<usize>>> = (0..rows.len())
+            .map(|y| {
+                (0..width)
+                    .map(|x| (rows[y][x] != 9).then_some(y * width + x))
+                    .collect()
+            })
+            .collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for y in 0..rows.len() {
+                for x in 0..width {
+                    for (nx, ny) in neighbors(rows, x, y) {
+                        if let (Some(label), Some(neighbor)) = (labels[y][x], labels[ny][nx]) {
+                            if neighbor < label {
+                                labels[y][x] = Some(neighbor);
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        labels
+    }
+
+    proptest! {
+        #[test]
+        fn matches_labelling_basins(rows in heightmap()) {
+            let text = rows
+                .iter()
+                .map(|row| row.iter().map(u32::to_string).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let grid = Day09::parse(&text).unwrap();
+            let low_points: Vec<(usize, usize)> = (0..rows.len())
+                .flat_map(|y| (0..rows[0].len()).map(move |x| (x, y)))
+                .filter(|&(x, y)| {
+                    neighbors(&rows, x, y)
+                        .into_iter()
+                        .all(|(nx, ny)| rows[ny][nx] > rows[y][x])
+                })
+                .collect();
+            let risk: u32 = low_points.iter().map(|&(x, y)| rows[y][x] + 1).sum();
+
+            let labels = basin_labels(&rows);
+            let mut sizes: Vec<u32> = low_points
+                .iter()
+                .map(|&(x, y)| labels.iter().flatten().filter(|&&label| label == labels[y][x]).count() as u32)
+                .collect();
+            sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+            prop_assert_eq!(Day09::part1(&grid).unwrap(), risk);
+            prop_assert_eq!(Day09::part2(&grid).unwrap(), sizes.iter().take(3).product::<u32>());
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Day 10: Syntax Scoring, scoring corrupted and incomplete lines of navigation subsystem brackets.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// The kind of a bracket: `()`, `[]`, `{}` or `<>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bracket {
+    Round,
+    Square,
+    Curly,
+    Angle,
+}
+
+impl Bracket {
+    /// Points for a line corrupted by this closing bracket.
+    pub fn syntax_error_score(self) -> u32 {
+        match self {
+            Bracket::Round => 3,
+            Bracket::Square => 57,
+            Bracket::Curly => 1197,
+            Bracket::Angle => 25137,
+        }
+    }
+
+    /// Points for closing this bracket when completing a line.
+    pub fn completion_score(self) -> u64 {
+        match self {
+            Bracket::Round => 1,
+            Bracket::Square => 2,
+            Bracket::Curly => 3,
+            Bracket::Angle => 4,
+        }
+    }
+}
+
+/// One character of a line of the navigation subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Open(Bracket),
+    Close(Bracket),
+}
+
+/// Checks the chunks of a line, returning the first closing bracket that doesn't match its
+/// opening bracket, or the brackets left open at the end of the line.
+pub fn check_line(line: &[Token]) -> Result<Vec<Bracket>, Bracket> {
+    let mut opens = vec![];
+
+    for &token in line {
+        match token {
+            Token::Open(bracket) => opens.push(bracket),
+            Token::Close(bracket) => {
+                if let Some(open) = opens.pop() {
+                    if open != bracket {
+                        return Err(bracket);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(opens)
+}
+
+/// Solves day 10 with the [`Solution`] trait.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<Token>>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AocResult<Vec<Vec<Token>>> {
+        Span::new(input)
+            .trim()
+            .lines()
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|(c, span)| match c {
+                        '(' => Ok(Token::Open(Bracket::Round)),
+                        '[' => Ok(Token::Open(Bracket::Square)),
+                        '{' => Ok(Token::Open(Bracket::Curly)),
+                        '<' => Ok(Token::Open(Bracket::Angle)),
+                        ')' => Ok(Token::Close(Bracket::Round)),
+                        ']' => Ok(Token::Close(Bracket::Square)),
+                        '}' => Ok(Token::Close(Bracket::Curly)),
+                        '>' => Ok(Token::Close(Bracket::Angle)),
+                        _ => Err(span.error("expected a bracket").into()),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Vec<Vec<Token>>) -> AocResult<u32> {
+        let mut points: u32 = 0;
+        for line in lines {
+            if let Err(bracket) = check_line(line) {
+                points = points
+                    .checked_add(bracket.syntax_error_score())
+                    .ok_or_else(|| AocError::overflow("syntax error score doesn't fit in a u32"))?;
+            }
+        }
+
+        Ok(points)
+    }
+
+    fn part2(lines: &Vec<Vec<Token>>) -> AocResult<u64> {
+        let mut scores = vec![];
+        for line in lines {
+            let Ok(opens) = check_line(line) else {
+                continue;
+            };
+
+            if !opens.is_empty() {
+                let mut points: u64 = 0;
+                for open in opens.iter().rev() {
+                    points = points
+                        .checked_mul(5)
+                        .and_then(|points| points.checked_add(open.completion_score()))
+                        .ok_or_else(|| {
+                            AocError::overflow("completion score doesn't fit in a u64")
+                        })?;
+                }
+                scores.push(points);
+            }
+        }
+
+        scores.sort();
+        scores
+            .get(scores.len() / 2)
+            .copied()
+            .ok_or_else(|| AocError::no_solution("no line is incomplete"))
+    }
+
+    /// `size` lines of about 100 brackets, half of them corrupted and the others incomplete.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        const OPEN: [char; 4] = ['(', '[', '{', '<'];
+        const CLOSE: [char; 4] = [')', ']', '}', '>'];
+        let mut input = String::new();
+        for i in 0..size {
+            // The first line is incomplete, so that there always is a middle completion score.
+            let mut corrupt = i > 0 && rng.chance(0.5);
+            let mut opens: Vec<usize> = vec![];
+            for _ in 0..rng.between(90, 110) {
+                // Lines stay less than 20 deep so that their completion scores fit in a u64.
+                if opens.is_empty() || (opens.len() < 20 && rng.chance(0.55)) {
+                    let bracket = rng.below(4);
+                    opens.push(bracket);
+                    input.push(OPEN[bracket]);
+                } else if let Some(open) = opens.pop() {
+                    if corrupt && rng.chance(0.02) {
+                        input.push(CLOSE[(open + rng.between(1, 3)) % 4]);
+                        corrupt = false;
+                    } else {
+                        input.push(CLOSE[open]);
+                    }
+                }
+            }
+            if corrupt {
+                input.push_str("(]");
+            } else if opens.is_empty() {
+                input.push('(');
+            }
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+common::register!(10, Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_only_corrupted_and_incomplete_lines() {
+        let lines = Day10::parse(&DAY.generate(200, 1).unwrap()).unwrap();
+        assert_eq!(lines.len(), 200);
+        assert!(matches!(check_line(&lines[0]), Ok(opens) if !opens.is_empty()));
+        assert!(lines
+            .iter()
+            .all(|line| !matches!(check_line(line), Ok(opens) if opens.is_empty())));
+        let corrupted = lines
+            .iter()
+            .filter(|line| check_line(line).is_err())
+            .count();
+        assert!((50..150).contains(&corrupted));
+    }
+
+    #[test]
+    fn rejects_unknown_characters() {
+        match Day10::parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<x[]>>(\n") {
+            Err(AocError::MalformedInput(err)) => {
+                assert_eq!((err.line, err.column, err.text.as_str()), (2, 18, "x"))
+            }
+            other => panic!("expected malformed input, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    const OPENS: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSES: [char; 4] = [')', ']', '}', '>'];
+
+    /// A line built from `actions`: below 4 opens a chunk, otherwise closes the innermost open
+    /// chunk with a bracket that may not match it.
+    fn line(actions: &[usize]) -> String {
+        let mut line = String::new();
+        let mut depth = 0;
+        for &action in actions {
+            if action < 4 || depth == 0 {
+                line.push(OPENS[action % 4]);
+                depth += 1;
+            } else {
+                line.push(CLOSES[action - 4]);
+                depth -= 1;
+            }
+        }
+        line
+    }
+
+    /// Removes matching pairs of brackets until none are left, leaving the first bracket that
+    /// doesn't match or the brackets that still need closing.
+    fn unmatched(line: &str) -> String {
+        let mut line = line.to_string();
+        loop {
+            let shorter = ["()", "[]", "{}", "<>"]
+                .iter()
+                .fold(line.clone(), |line, pair| line.replace(pair, ""));
+            if shorter == line {
+                return line;
+            }
+            line = shorter;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matches_removing_pairs(lines in vec(vec(0..8usize, 1..30), 1..10)) {
+            let lines: Vec<String> = lines.iter().map(|actions| line(actions)).collect();
+            let parsed = Day10::parse(&lines.join("\n")).unwrap();
+
+            let mut syntax_error_score = 0;
+            let mut completion_scores = vec![];
+            for line in &lines {
+                let left = unmatched(line);
+                match left.chars().find(|c| CLOSES.contains(c)) {
+                    Some(close) => {
+                        syntax_error_score += [3, 57, 1197, 25137]
+                            [CLOSES.iter().position(|&c| c == close).unwrap()]
+                    }
+                    None if !left.is_empty() => completion_scores.push(
+                        left.chars()
+                            .rev()
+                            .map(|open| OPENS.iter().position(|&c| c == open).unwrap() as u64 + 1)
+                            .fold(0, |score, points| score * 5 + points),
+                    ),
+                    None => {}
+                }
+            }
+            completion_scores.sort_unstable();
+
+            prop_assert_eq!(Day10::part1(&parsed).unwrap(), syntax_error_score);
+            match completion_scores.get(completion_scores.len() / 2) {
+                Some(&middle) => prop_assert_eq!(Day10::part2(&parsed).unwrap(), middle),
+                None => prop_assert!(Day10::part2(&parsed).is_err()),
+            }
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Day 11: Dumbo Octopus, simulating a [`Cavern`] of flashing octopuses until they all flash at
+//! once.
+
+use common::generate::Rng;
+use common::viz::{Cell, Recording, Rgb};
+use common::{AocError, AocResult, Grid, Solution};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// A grid of octopuses and their energy levels, parsed from one row of digits per line.
+#[derive(Clone)]
+pub struct Cavern {
+    grid: Grid<u8>,
+}
+
+impl FromStr for Cavern {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> AocResult<Self> {
+        Ok(Cavern {
+            grid: Grid::parse_digits(s)?,
+        })
+    }
+}
+
+impl Display for Cavern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            for &energy in row {
+                if energy > 9 {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, "{}", energy)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Cavern {
+    /// The energy level of every octopus.
+    pub fn energy(&self) -> &Grid<u8> {
+        &self.grid
+    }
+
+    /// Raises the energy of every octopus, lets every one above 9 flash and raise the energy of
+    /// its neighbours, and returns the positions of the octopuses that flashed.
+    pub fn step(&mut self) -> HashSet<(usize, usize)> {
+        let mut flashed = HashSet::new();
+
+        for energy in self.grid.cells_mut() {
+            *energy += 1;
+        }
+
+        for (x, y) in self.grid.positions() {
+            let mut to_visit = vec![];
+            if self.grid[(x, y)] > 9 && !flashed.contains(&(x, y)) {
+                flashed.insert((x, y));
+                to_visit.append(&mut self.flash(x, y));
+
+                while let Some((x, y)) = to_visit.pop() {
+                    if self.grid[(x, y)] > 9 && !flashed.contains(&(x, y)) {
+                        flashed.insert((x, y));
+                        to_visit.append(&mut self.flash(x, y));
+                    }
+                }
+
+                self.grid[(x, y)] = 0;
+                for &position in &flashed {
+                    self.grid[position] = 0;
+                }
+            }
+        }
+
+        flashed
+    }
+
+    fn flash(&mut self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut to_visit = vec![];
+
+        for neighbor in self.grid.neighbors8(x, y) {
+            self.grid[neighbor] += 1;
+            to_visit.push(neighbor);
+        }
+
+        to_visit
+    }
+}
+
+/// Steps simulated at most while waiting for the octopuses to all flash at once, which they
+/// never do in most grids.
+const MAX_STEPS: i32 = 10_000;
+
+/// Steps recorded at most by the visualization, in case the octopuses never all flash at once.
+const MAX_RECORDED_STEPS: usize = 1000;
+
+/// Steps a generated grid may take to flash all at once.
+const MAX_GENERATED_STEPS: usize = 1000;
+
+/// Octopuses that just flashed are drawn bright, the others darker the less energy they have.
+fn energy_cell(&energy: &u8) -> Cell {
+    let glyph = char::from_digit(u32::from(energy.min(9)), 10).unwrap_or('#');
+    if energy == 0 {
+        Cell::colored(glyph, Rgb(255, 240, 150))
+    } else {
+        let level = 40 + energy.min(9) * 15;
+        Cell::colored(glyph, Rgb(0, level / 2, level))
+    }
+}
+
+/// Solves day 11 with the [`Solution`] trait.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Cavern;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Cavern> {
+        Cavern::from_str(input)
+    }
+
+    fn part1(cavern: &Cavern) -> AocResult<usize> {
+        let mut cavern = cavern.clone();
+
+        let mut flashes = 0;
+        for _ in 0..100 {
+            flashes += cavern.step().len();
+        }
+
+        Ok(flashes)
+    }
+
+    fn part2(cavern: &Cavern) -> AocResult<i32> {
+        let mut cavern = cavern.clone();
+
+        for step in 1..=MAX_STEPS {
+            let flashed = cavern.step();
+            if flashed.len() == cavern.grid.len() {
+                return Ok(step);
+            }
+        }
+
+        Err(AocError::no_solution(
+            "the octopuses never all flash at once",
+        ))
+    }
+
+    fn visualize(cavern: &Cavern) -> AocResult<Option<Recording>> {
+        let mut cavern = cavern.clone();
+        let mut recording = Recording::new();
+        recording.push_grid("Step 0", &cavern.grid, energy_cell);
+
+        for step in 1..=MAX_RECORDED_STEPS {
+            let flashed = cavern.step();
+            recording.push_grid(
+                format!("Step {}: {} flashes", step, flashed.len()),
+                &cavern.grid,
+                energy_cell,
+            );
+            if flashed.len() == cavern.grid.len() {
+                break;
+            }
+        }
+
+        Ok(Some(recording))
+    }
+
+    /// A `size` by `size` grid of octopuses with energy levels from 3 to 9. Most random grids
+    /// never all flash at once, so grids are drawn until one does within 1000 steps.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        loop {
+            let mut input = String::new();
+            for _ in 0..size {
+                input.extend((0..size).map(|_| char::from(b'0' + rng.between(3, 9) as u8)));
+                input.push('\n');
+            }
+            let mut cavern: Cavern = input.parse().ok()?;
+            if (0..MAX_GENERATED_STEPS).any(|_| cavern.step().len() == cavern.grid.len()) {
+                return Some(input);
+            }
+        }
+    }
+}
+
+common::register!(11, Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = include_str!("input/test.txt");
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_grids_that_synchronize() {
+        let cavern = Day11::parse(&DAY.generate(20, 1).unwrap()).unwrap();
+        assert_eq!(cavern.energy().width(), 20);
+        assert!(Day11::part2(&cavern).unwrap() as usize <= MAX_GENERATED_STEPS);
+    }
+
+    #[test]
+    fn gives_up_if_never_all_flash() {
+        let cavern = Day11::parse("00002\n").unwrap();
+        assert!(matches!(
+            Day11::part2(&cavern),
+            Err(AocError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn records_every_step_until_all_flash() {
+        let cavern = Day11::parse(TEST_INPUT).unwrap();
+        let recording = Day11::visualize(&cavern).unwrap().unwrap();
+        let frames = recording.frames();
+        assert_eq!(frames.len(), 196);
+        assert_eq!(frames[195].caption, "Step 195: 100 flashes");
+        assert!(frames[195].cells.cells().all(|(_, cell)| cell.glyph == '0'));
+    }
+
+    /// Runs a step by scanning the whole grid for octopuses ready to flash until none are.
+    fn naive_step(rows: &mut [Vec<u8>]) -> usize {
+        let (width, height) = (rows[0].len() as i32, rows.len() as i32);
+        let mut flashed = vec![vec![false; width as usize]; height as usize];
+        for energy in rows.iter_mut().flatten() {
+            *energy += 1;
+        }
+        loop {
+            let ready = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .find(|&(x, y)| {
+                    rows[y as usize][x as usize] > 9 && !flashed[y as usize][x as usize]
+                });
+            let Some((x, y)) = ready else {
+                break;
+            };
+            flashed[y as usize][x as usize] = true;
+            for (dx, dy) in (-1..=1).flat_map(|dx| (-1..=1).map(move |dy| (dx, dy))) {
+                let (nx, ny) = (x + dx, y + dy);
+                if (dx, dy) != (0, 0) && (0..width).contains(&nx) && (0..height).contains(&ny) {
+                    rows[ny as usize][nx as usize] += 1;
+                }
+            }
+        }
+        for (row, flashed) in rows.iter_mut().zip(&flashed) {
+            for (energy, &flashed) in row.iter_mut().zip(flashed) {
+                if flashed {
+                    *energy = 0;
+                }
+            }
+        }
+        flashed.iter().flatten().filter(|&&flashed| flashed).count()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_scanning_for_flashes(
+            mut rows in (1..7usize, 1..7usize)
+                .prop_flat_map(|(width, height)| vec(vec(0..=9u8, width), height)),
+        ) {
+            let text = rows
+                .iter()
+                .map(|row| row.iter().map(u8::to_string).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let cavern = Day11::parse(&text).unwrap();
+            let flashes: usize = (0..100).map(|_| naive_step(&mut rows)).sum();
+
+            prop_assert_eq!(Day11::part1(&cavern).unwrap(), flashes);
+        }
+    }
+}
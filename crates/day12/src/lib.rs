@@ -0,0 +1,275 @@
+//! Day 12: Passage Pathing, counting the paths through a [`CaveSystem`] that visit small caves a
+//! limited number of times.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{search, AocError, AocResult, Solution};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// A cave, named in lowercase if it is small and in uppercase if it is big.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cave {
+    Start,
+    End,
+    /// A cave that paths may only go through once.
+    Small(Arc<str>),
+    /// A cave that paths may go through any number of times.
+    Big(Arc<str>),
+}
+
+impl From<&str> for Cave {
+    fn from(s: &str) -> Self {
+        if s == "start" {
+            Cave::Start
+        } else if s == "end" {
+            Cave::End
+        } else {
+            match s.chars().all(|c| c.is_uppercase()) {
+                true => Cave::Big(s.into()),
+                false => Cave::Small(s.into()),
+            }
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Cave::Start => write!(f, "start"),
+            Cave::End => write!(f, "end"),
+            Cave::Small(s) | Cave::Big(s) => write!(f, "{}", s),
+        }?;
+        Ok(())
+    }
+}
+
+/// The caves and the passages between them, parsed from one `a-b` passage per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaveSystem {
+    connections: HashMap<Cave, Vec<Cave>>,
+}
+
+impl TryFrom<&str> for CaveSystem {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> AocResult<Self> {
+        let mut connections = HashMap::new();
+
+        for line in Span::new(s).trim().lines() {
+            let (origin, destination) = line.split_once("-")?;
+            let origin: Cave = origin.as_str().into();
+            let destination: Cave = destination.as_str().into();
+            let entry = connections.entry(origin.clone()).or_insert_with(Vec::new);
+            entry.push(destination.clone());
+            let entry = connections.entry(destination).or_insert_with(Vec::new);
+            entry.push(origin);
+        }
+
+        Ok(CaveSystem { connections })
+    }
+}
+
+impl CaveSystem {
+    /// The caves connected to `cave` by a passage.
+    pub fn neighbors(&self, cave: &Cave) -> &[Cave] {
+        self.connections
+            .get(cave)
+            .map_or(&[], |caves| caves.as_slice())
+    }
+
+    /// Every path from the start to the end that visits small caves at most once, except for a
+    /// single small cave that may be visited twice if `one_small_twice` is set.
+    pub fn get_paths(&self, one_small_twice: bool) -> Vec<Vec<Cave>> {
+        search::all_paths(
+            Cave::Start,
+            |path: &[Cave]| {
+                let twice_used = path
+                    .iter()
+                    .enumerate()
+                    .any(|(i, cave)| matches!(cave, Cave::Small(_)) && path[..i].contains(cave));
+                let origin = &path[path.len() - 1];
+                self.connections
+                    .get(origin)
+                    .into_iter()
+                    .flatten()
+                    .filter(|destination| match destination {
+                        Cave::Start => false,
+                        Cave::End | Cave::Big(_) => true,
+                        Cave::Small(_) => {
+                            !path.contains(destination) || (one_small_twice && !twice_used)
+                        }
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            },
+            |cave| *cave == Cave::End,
+        )
+    }
+}
+
+/// Solves day 12 with the [`Solution`] trait.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = CaveSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<CaveSystem> {
+        input.try_into()
+    }
+
+    fn part1(cave_system: &CaveSystem) -> AocResult<usize> {
+        let paths = cave_system.get_paths(false);
+
+        Ok(paths.len())
+    }
+
+    fn part2(cave_system: &CaveSystem) -> AocResult<usize> {
+        let paths = cave_system.get_paths(true);
+
+        Ok(paths.len())
+    }
+
+    /// A chain of `size` big caves from the start to the end, each joined to the next one by two
+    /// small caves, with some small dead ends. The number of paths more than doubles with every
+    /// big cave, so sizes much over 10 take a long time.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        // Spreadsheet style names, `a` to `z`, then `aa`...
+        let name = |mut index: usize| {
+            let mut name = vec![];
+            loop {
+                name.push(b'a' + (index % 26) as u8);
+                index /= 26;
+                if index == 0 {
+                    break;
+                }
+                index -= 1;
+            }
+            name.reverse();
+            String::from_utf8(name).unwrap()
+        };
+        let mut small_caves = 0;
+        let mut small_cave = || {
+            small_caves += 1;
+            name(small_caves - 1)
+        };
+
+        let size = size.max(1);
+        let big_caves: Vec<String> = (0..size).map(|i| name(i).to_uppercase()).collect();
+        let mut passages = vec![format!("start-{}", big_caves[0])];
+        for (i, big_cave) in big_caves.iter().enumerate() {
+            let next = big_caves.get(i + 1).map_or("end", |next| next.as_str());
+            for _ in 0..2 {
+                let small_cave = small_cave();
+                passages.push(format!("{}-{}", big_cave, small_cave));
+                passages.push(format!("{}-{}", small_cave, next));
+            }
+            if rng.chance(0.5) {
+                passages.push(format!("{}-{}", small_cave(), big_cave));
+            }
+        }
+        rng.shuffle(&mut passages);
+        Some(passages.join("\n") + "\n")
+    }
+}
+
+common::register!(12, Day12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_valid_input() {
+        let input = DAY.generate(3, 1).unwrap();
+        let cave_system = Day12::parse(&input).unwrap();
+        assert!(Day12::part1(&cave_system).unwrap() >= 8);
+        assert!(Day12::part2(&cave_system).unwrap() > Day12::part1(&cave_system).unwrap());
+    }
+
+    const CAVES: [&str; 8] = ["start", "end", "a", "b", "c", "d", "A", "B"];
+
+    /// Every connection the generator may pick, leaving out big caves next to each other,
+    /// which would allow endless paths.
+    fn possible_connections() -> Vec<(&'static str, &'static str)> {
+        let mut connections = vec![];
+        for (i, &a) in CAVES.iter().enumerate() {
+            for &b in &CAVES[i + 1..] {
+                if !(a == a.to_uppercase() && b == b.to_uppercase()) {
+                    connections.push((a, b));
+                }
+            }
+        }
+        connections
+    }
+
+    /// Counts the paths to the end recursively, straight from the puzzle's rules.
+    fn count_paths(
+        connections: &[(&str, &str)],
+        path: &mut Vec<&'static str>,
+        revisit_left: bool,
+    ) -> usize {
+        let cave = *path.last().unwrap();
+        if cave == "end" {
+            return 1;
+        }
+        let mut paths = 0;
+        for &(a, b) in connections {
+            let next = match (a == cave, b == cave) {
+                (true, _) => b,
+                (_, true) => a,
+                _ => continue,
+            };
+            let small = next == next.to_lowercase();
+            let visited = path.contains(&next);
+            if next == "start" || (small && visited && !revisit_left) {
+                continue;
+            }
+            path.push(CAVES.iter().find(|&&c| c == next).unwrap());
+            paths += count_paths(connections, path, revisit_left && !(small && visited));
+            path.pop();
+        }
+        paths
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn matches_counting_paths_recursively(
+            picked in vec(any::<bool>(), possible_connections().len()),
+        ) {
+            let connections: Vec<_> = possible_connections()
+                .into_iter()
+                .zip(picked)
+                .filter_map(|(connection, picked)| picked.then_some(connection))
+                .collect();
+            let text = connections
+                .iter()
+                .map(|(a, b)| format!("{}-{}", a, b))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let cave_system = Day12::parse(&text).unwrap();
+
+            prop_assert_eq!(
+                Day12::part1(&cave_system).unwrap(),
+                count_paths(&connections, &mut vec!["start"], false)
+            );
+            prop_assert_eq!(
+                Day12::part2(&cave_system).unwrap(),
+                count_paths(&connections, &mut vec!["start"], true)
+            );
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Day 13: Transparent Origami, folding a sheet of transparent [`Paper`] along each [`Fold`] until
+//! it shows a code.
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::viz::{Cell, Recording, Rgb};
+use common::{AocError, AocResult, Grid, Point, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// The axis a fold line runs across: `x` folds the right half over to the left, `y` folds the
+/// bottom half up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// A `fold along x=5` instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fold {
+    pub axis: Axis,
+    /// The coordinate of the fold line along `axis`.
+    pub index: i32,
+}
+
+impl FromStr for Axis {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fold along x" => Ok(Self::X),
+            "fold along y" => Ok(Self::Y),
+            _ => Err("invalid axis"),
+        }
+    }
+}
+
+impl Display for Fold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let axis = match self.axis {
+            Axis::X => 'x',
+            Axis::Y => 'y',
+        };
+        write!(f, "fold along {}={}", axis, self.index)
+    }
+}
+
+impl Fold {
+    pub fn parse(span: Span) -> AocResult<Self> {
+        let (axis, index) = span.pair("=")?;
+        Ok(Self { axis, index })
+    }
+}
+
+/// A sheet of transparent paper with dots on it.
+#[derive(Debug, Clone)]
+pub struct Paper {
+    dots: HashMap<Point, bool>,
+}
+
+impl Paper {
+    /// Parses one `x,y` dot per line.
+    pub fn parse(span: Span) -> AocResult<Self> {
+        let mut dots = HashMap::new();
+
+        for line in span.lines() {
+            dots.insert(line.point()?, true);
+        }
+
+        Ok(Self { dots })
+    }
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(max) = self.max() else {
+            return Ok(());
+        };
+        let min = self.top_left();
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let is_dot = self.dots.get(&Point { x, y }).unwrap_or(&false);
+                write!(f, "{}", if *is_dot { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Paper {
+    /// The bottom right corner of the dots, or `None` if there are none.
+    pub fn max(&self) -> Option<Point> {
+        let x = self.dots.keys().map(|p| p.x).max()?;
+        let y = self.dots.keys().map(|p| p.y).max()?;
+
+        Some(Point::new(x, y))
+    }
+
+    /// The top left corner of the area the paper is drawn in: the origin, or further up and left
+    /// if folds along lines before the middle moved dots to negative coordinates.
+    fn top_left(&self) -> Point {
+        let x = self.dots.keys().map(|p| p.x).min().unwrap_or(0);
+        let y = self.dots.keys().map(|p| p.y).min().unwrap_or(0);
+
+        Point::new(x.min(0), y.min(0))
+    }
+
+    /// The dots as cells of a visualization frame, with dots drawn in gold.
+    pub fn cells(&self) -> Grid<Cell> {
+        let min = self.top_left();
+        let max = self.max().unwrap_or(Point::ZERO);
+        let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+        Grid::from_fn(width, height, |x, y| {
+            let point = min + Point::new(x as i32, y as i32);
+            if self.dots.get(&point) == Some(&true) {
+                Cell::colored('#', Rgb(255, 200, 50))
+            } else {
+                Cell::new('.')
+            }
+        })
+    }
+
+    /// The number of dots visible, counting dots that landed on each other once.
+    pub fn dot_count(&self) -> usize {
+        self.dots.values().filter(|&is_dot| *is_dot).count()
+    }
+
+    /// Folds the paper along `fold`, dropping the dots on the fold line.
+    pub fn fold(&mut self, fold: &Fold) {
+        let mut new_dots = HashMap::new();
+
+        for (point, is_dot) in &self.dots {
+            let new_point = match fold.axis {
+                Axis::X => {
+                    if point.x == fold.index {
+                        continue;
+                    } else if point.x < fold.index {
+                        *point
+                    } else {
+                        Point::new(fold.index - (point.x - fold.index), point.y)
+                    }
+                }
+                Axis::Y => {
+                    if point.y == fold.index {
+                        continue;
+                    } else if point.y < fold.index {
+                        *point
+                    } else {
+                        Point::new(point.x, fold.index - (point.y - fold.index))
+                    }
+                }
+            };
+            new_dots.insert(new_point, *is_dot);
+        }
+
+        self.dots = new_dots;
+    }
+}
+
+/// The sheet as it comes out of the manual, and the folds to make in order.
+pub struct Instructions {
+    paper: Paper,
+    folds: Vec<Fold>,
+}
+
+impl Instructions {
+    pub fn paper(&self) -> &Paper {
+        &self.paper
+    }
+
+    pub fn folds(&self) -> &[Fold] {
+        &self.folds
+    }
+}
+
+/// Solves day 13 with the [`Solution`] trait.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Instructions;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AocResult<Instructions> {
+        let [dots, folds] = Span::new(input).sections_exact()?;
+        let paper = Paper::parse(dots)?;
+        let folds = folds.lines().map(Fold::parse).collect::<AocResult<_>>()?;
+
+        Ok(Instructions { paper, folds })
+    }
+
+    fn part1(instructions: &Instructions) -> AocResult<usize> {
+        let mut paper = instructions.paper.clone();
+
+        let first = instructions
+            .folds
+            .first()
+            .ok_or_else(|| AocError::no_solution("there are no folds"))?;
+        paper.fold(first);
+
+        Ok(paper.dot_count())
+    }
+
+    fn part2(instructions: &Instructions) -> AocResult<String> {
+        let mut paper = instructions.paper.clone();
+
+        for fold in &instructions.folds {
+            paper.fold(fold);
+        }
+
+        Ok(format!("\n{}", paper))
+    }
+
+    fn visualize(instructions: &Instructions) -> AocResult<Option<Recording>> {
+        let mut paper = instructions.paper.clone();
+        let mut recording = Recording::new();
+        recording.push("Unfolded", paper.cells());
+
+        for (i, fold) in instructions.folds.iter().enumerate() {
+            paper.fold(fold);
+            recording.push(format!("Fold {}: {}", i + 1, fold), paper.cells());
+        }
+
+        Ok(Some(recording))
+    }
+
+    /// `size` dots on a sheet that folds down to a 40 by 6 code, folded as many times as it takes
+    /// to fit that many dots.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        let (mut width, mut height) = (40, 6);
+        // Unfolding the sheet doubles it along the axis, with the fold line in the middle.
+        let mut unfolds = vec![];
+        while ((width * height) as usize) < 2 * size || unfolds.len() < 2 {
+            let fold = if width <= height * 3 {
+                width = width * 2 + 1;
+                Fold {
+                    axis: Axis::X,
+                    index: width / 2,
+                }
+            } else {
+                height = height * 2 + 1;
+                Fold {
+                    axis: Axis::Y,
+                    index: height / 2,
+                }
+            };
+            unfolds.push(fold);
+        }
+
+        // Dots start out on the folded code and land on either side of every fold line as it is
+        // unfolded, so none of them ever end up on a fold line.
+        let mut dots = HashSet::new();
+        let mut input = String::new();
+        while dots.len() < size {
+            let mut dot = Point::new(rng.below(40) as i32, rng.below(6) as i32);
+            for fold in &unfolds {
+                if rng.chance(0.5) {
+                    match fold.axis {
+                        Axis::X => dot.x = 2 * fold.index - dot.x,
+                        Axis::Y => dot.y = 2 * fold.index - dot.y,
+                    }
+                }
+            }
+            if dots.insert(dot) {
+                input.push_str(&format!("{},{}\n", dot.x, dot.y));
+            }
+        }
+        input.push('\n');
+        for fold in unfolds.iter().rev() {
+            input.push_str(&format!("{}\n", fold));
+        }
+        Some(input)
+    }
+}
+
+common::register!(13, Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = include_str!("input/test.txt");
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_valid_input() {
+        let input = DAY.generate(1000, 1).unwrap();
+        let instructions = Day13::parse(&input).unwrap();
+        assert_eq!(instructions.paper().dot_count(), 1000);
+        assert!(Day13::part1(&instructions).unwrap() < 1000);
+        let code = Day13::part2(&instructions).unwrap();
+        assert!(code.lines().skip(1).all(|line| line.len() <= 40));
+    }
+
+    #[test]
+    fn records_every_fold() {
+        let instructions = Day13::parse(TEST_INPUT).unwrap();
+        let recording = Day13::visualize(&instructions).unwrap().unwrap();
+        let captions: Vec<&str> = recording
+            .frames()
+            .iter()
+            .map(|frame| frame.caption.as_str())
+            .collect();
+        assert_eq!(
+            captions,
+            vec![
+                "Unfolded",
+                "Fold 1: fold along y=7",
+                "Fold 2: fold along x=5"
+            ]
+        );
+        let last = &recording.frames()[2].cells;
+        assert_eq!(
+            last.map(|cell| cell.glyph).to_string(),
+            Day13::part2(&instructions).unwrap()[1..]
+        );
+    }
+
+    #[test]
+    fn draws_negative_coordinates() {
+        let mut paper = Paper::parse(Span::new("3,0\n0,1\n")).unwrap();
+        paper.fold(&Fold {
+            axis: Axis::X,
+            index: 1,
+        });
+        assert_eq!(paper.to_string(), "#.\n.#\n");
+        let cells = paper.cells().map(|cell| cell.glyph);
+        assert_eq!(cells.to_string(), "#.\n.#\n");
+    }
+
+    /// Folds a sheet of dots held as rows of booleans, dropping the row or column of the fold.
+    fn fold_sheet(sheet: Vec<Vec<bool>>, along_x: bool, index: usize) -> Vec<Vec<bool>> {
+        let (width, height) = (sheet[0].len(), sheet.len());
+        let (new_width, new_height) = if along_x {
+            (index, height)
+        } else {
+            (width, index)
+        };
+        let mut folded = vec![vec![false; new_width]; new_height];
+        for (y, row) in sheet.iter().enumerate() {
+            for (x, &dot) in row.iter().enumerate() {
+                let (x, y) = match along_x {
+                    true if x == index => continue,
+                    true if x > index => (2 * index - x, y),
+                    false if y == index => continue,
+                    false if y > index => (x, 2 * index - y),
+                    _ => (x, y),
+                };
+                folded[y][x] |= dot;
+            }
+        }
+        folded
+    }
+
+    fn render(sheet: &[Vec<bool>]) -> String {
+        let dots: Vec<(usize, usize)> = sheet
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &dot)| dot)
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect();
+        let mut rendered = "\n".to_string();
+        if let (Some(max_x), Some(max_y)) = (
+            dots.iter().map(|&(x, _)| x).max(),
+            dots.iter().map(|&(_, y)| y).max(),
+        ) {
+            for row in &sheet[..=max_y] {
+                for &dot in &row[..=max_x] {
+                    rendered.push(if dot { '#' } else { '.' });
+                }
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+
+    proptest! {
+        #[test]
+        fn matches_folding_a_sheet(
+            dots in vec((0..20usize, 0..20usize), 1..40),
+            folds in vec(any::<bool>(), 1..5),
+        ) {
+            let mut sheet = vec![vec![false; 20]; 20];
+            for &(x, y) in &dots {
+                sheet[y][x] = true;
+            }
+
+            let mut text: String = dots.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+            text.push('\n');
+            let mut sheets = vec![];
+            for along_x in folds {
+                let size = if along_x { sheet[0].len() } else { sheet.len() };
+                if size < 2 {
+                    continue;
+                }
+                let index = size / 2;
+                text.push_str(&format!("fold along {}={}\n", if along_x { 'x' } else { 'y' }, index));
+                sheet = fold_sheet(sheet, along_x, index);
+                sheets.push(sheet.clone());
+            }
+            let instructions = Day13::parse(&text).unwrap();
+
+            let first_dots = sheets[0].iter().flatten().filter(|&&dot| dot).count();
+            prop_assert_eq!(Day13::part1(&instructions).unwrap(), first_dots);
+            prop_assert_eq!(Day13::part2(&instructions).unwrap(), render(&sheet));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Day 14: Extended Polymerization, growing a polymer by the pair insertion rules of a [`Manual`].
+
+use common::generate::Rng;
+use common::parse::Span;
+use common::{AocError, AocResult, Solution};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// The polymer template and the pair insertion rules from the submarine's manual.
+pub struct Manual {
+    template: String,
+    rules: HashMap<(char, char), char>,
+}
+
+impl Manual {
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// The element inserted between each pair of adjacent elements that has a rule.
+    pub fn rules(&self) -> &HashMap<(char, char), char> {
+        &self.rules
+    }
+}
+
+/// Adds `count` to the count of `key`, failing if the total no longer fits in a `usize`.
+fn add_count<K: Eq + Hash>(counts: &mut HashMap<K, usize>, key: K, count: usize) -> AocResult<()> {
+    let total = counts.entry(key).or_insert(0);
+    *total = total
+        .checked_add(count)
+        .ok_or_else(|| AocError::overflow("element count doesn't fit in a usize"))?;
+    Ok(())
+}
+
+/// The count of the most common element minus the count of the least common one.
+fn count_spread(counts: &HashMap<char, usize>) -> AocResult<usize> {
+    let max = counts.values().max();
+    let min = counts.values().min();
+    match (max, min) {
+        (Some(max), Some(min)) => Ok(max - min),
+        _ => Err(AocError::no_solution("the polymer is empty")),
+    }
+}
+
+/// Inserts the elements one by one for `steps` steps, returning how many more of the most
+/// common element than of the least common one the polymer ends up with.
+pub fn grow_polymer(manual: &Manual, steps: usize) -> AocResult<usize> {
+    let Manual { template, rules } = manual;
+
+    let span = common::span!("read template");
+    let mut counts = HashMap::new();
+    for c in template.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    let mut polymer = template.chars().collect::<Vec<char>>();
+    drop(span);
+
+    for _ in 0..steps {
+        let _step = common::span!("step");
+        let span = common::span!("match rules");
+        let mut insertions = vec![];
+        for (i, pair) in polymer.windows(2).enumerate() {
+            if let Some(insertion) = rules.get(&(pair[0], pair[1])) {
+                insertions.push((i + 1, *insertion));
+                *counts.entry(*insertion).or_insert(0) += 1;
+            }
+        }
+        drop(span);
+
+        let _span = common::span!("insert");
+        for (i, (insertion_index, char)) in insertions.into_iter().enumerate() {
+            polymer.insert(insertion_index + i, char);
+        }
+    }
+
+    count_spread(&counts)
+}
+
+/// Like [`grow_polymer`], but only counting the pairs of adjacent elements, which doesn't grow
+/// with the length of the polymer.
+pub fn count_pairs(manual: &Manual, steps: usize) -> AocResult<usize> {
+    let Manual { template, rules } = manual;
+
+    let polymer = template.chars().collect::<Vec<char>>();
+    let mut pair_counts: HashMap<(char, char), usize> = HashMap::new();
+    for pair in polymer.windows(2) {
+        *pair_counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+
+    for _ in 0..steps {
+        let mut new_pair_counts = HashMap::new();
+        for (pair, count) in pair_counts.into_iter() {
+            match rules.get(&pair) {
+                Some(insertion) => {
+                    add_count(&mut new_pair_counts, (pair.0, *insertion), count)?;
+                    add_count(&mut new_pair_counts, (*insertion, pair.1), count)?;
+                }
+                None => add_count(&mut new_pair_counts, pair, count)?,
+            }
+        }
+        pair_counts = new_pair_counts;
+    }
+
+    let mut counts = HashMap::new();
+    for ((first, _), count) in pair_counts.iter() {
+        add_count(&mut counts, *first, *count)?;
+    }
+    if let Some(&last) = polymer.last() {
+        add_count(&mut counts, last, 1)?;
+    }
+
+    count_spread(&counts)
+}
+
+/// Solves day 14 with the [`Solution`] trait.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Manual> {
+        let [template, rules_section] = Span::new(input).sections_exact()?;
+
+        let mut rules: HashMap<(char, char), char> = HashMap::new();
+        for rule in rules_section.lines() {
+            let (pair, insertion) = rule.split_once(" -> ")?;
+            let pair = match pair.as_str().chars().collect::<Vec<_>>()[..] {
+                [first, second] => (first, second),
+                _ => return Err(pair.error("expected a pair of elements").into()),
+            };
+            rules.insert(pair, insertion.parse()?);
+        }
+
+        Ok(Manual {
+            template: template.as_str().to_string(),
+            rules,
+        })
+    }
+
+    fn part1(manual: &Manual) -> AocResult<usize> {
+        grow_polymer(manual, 10)
+    }
+
+    fn part2(manual: &Manual) -> AocResult<usize> {
+        count_pairs(manual, 40)
+    }
+
+    /// A template of `size` elements and a rule for every pair of the 10 elements, like the real
+    /// manual.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+        let template: String = (0..size.max(2)).map(|_| *rng.choose(&ELEMENTS)).collect();
+        let mut input = format!("{}\n\n", template);
+        for first in ELEMENTS {
+            for second in ELEMENTS {
+                let insertion = rng.choose(&ELEMENTS);
+                input.push_str(&format!("{}{} -> {}\n", first, second, insertion));
+            }
+        }
+        Some(input)
+    }
+}
+
+common::register!(14, Day14);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::option;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_manuals_with_every_rule() {
+        let manual = Day14::parse(&DAY.generate(5, 1).unwrap()).unwrap();
+        assert_eq!(manual.template().len(), 5);
+        assert_eq!(manual.rules().len(), 100);
+        assert_eq!(
+            count_pairs(&manual, 12).unwrap(),
+            grow_polymer(&manual, 12).unwrap()
+        );
+    }
+
+    const ELEMENTS: [char; 4] = ['B', 'C', 'H', 'N'];
+
+    proptest! {
+        #[test]
+        fn counting_pairs_matches_growing_the_polymer(
+            template in vec(0..4usize, 1..8),
+            insertions in vec(option::of(0..4usize), 16),
+            steps in 0..8usize,
+        ) {
+            let mut text: String = template.iter().map(|&e| ELEMENTS[e]).collect();
+            text.push('\n');
+            for (pair, insertion) in insertions.iter().enumerate() {
+                if let Some(insertion) = insertion {
+                    let (first, second) = (ELEMENTS[pair / 4], ELEMENTS[pair % 4]);
+                    text.push_str(&format!("\n{}{} -> {}", first, second, ELEMENTS[*insertion]));
+                }
+            }
+            prop_assume!(insertions.iter().any(Option::is_some));
+            let manual = Day14::parse(&text).unwrap();
+
+            prop_assert_eq!(
+                count_pairs(&manual, steps).unwrap(),
+                grow_polymer(&manual, steps).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Day 15: Chiton, finding the path with the lowest total risk through a cave of chitons, and
+//! through the cave tiled five times.
+
+use common::generate::Rng;
+use common::search::{astar, Path};
+use common::{AocError, AocResult, Grid, Point, Solution};
+
+/// The puzzle input for this day.
+pub const INPUT: &str = include_str!("input/input.txt");
+
+/// The path from the top left to the bottom right with the lowest total risk, which doesn't
+/// count the risk of the starting position.
+pub fn find_shortest_path(grid: &Grid<usize>) -> AocResult<Path<Point, usize>> {
+    let _span = common::span!("find_shortest_path");
+    let destination = Point::new(grid.width() as i32 - 1, grid.height() as i32 - 1);
+    astar(
+        Point::ZERO,
+        |&point| {
+            point
+                .neighbors4()
+                .filter_map(|neighbor| Some((neighbor, *grid.get_point(neighbor)?)))
+        },
+        |&point| point.manhattan_distance(destination) as usize,
+        |&point| point == destination,
+    )
+    .ok_or_else(|| AocError::no_solution("no path reaches the bottom right"))
+}
+
+/// Tiles `grid` `n` times in each direction, with the risk of each tile one higher than the one
+/// to its left or above, wrapping around from 9 back to 1.
+pub fn expand_grid(grid: &Grid<usize>, n: usize) -> Grid<usize> {
+    let _span = common::span!("expand_grid");
+    let (width, height) = (grid.width(), grid.height());
+    Grid::from_fn(width * n, height * n, |x, y| {
+        let mut orig = grid[(x % width, y % height)];
+        orig += x / width + y / height;
+        if orig > 9 {
+            orig = (orig % 10) + 1;
+        }
+        orig
+    })
+}
+
+/// The total risk of `path` as an answer.
+fn total_risk(path: &Path<Point, usize>) -> AocResult<i32> {
+    i32::try_from(path.cost).map_err(|_| AocError::overflow("total risk doesn't fit in an i32"))
+}
+
+/// Solves day 15 with the [`Solution`] trait.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<usize>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Grid<usize>> {
+        Ok(Grid::parse_digits(input)?)
+    }
+
+    fn part1(grid: &Grid<usize>) -> AocResult<i32> {
+        let path = find_shortest_path(grid)?;
+        total_risk(&path)
+    }
+
+    fn part2(grid: &Grid<usize>) -> AocResult<i32> {
+        let grid = expand_grid(grid, 5);
+
+        let path = find_shortest_path(&grid)?;
+        total_risk(&path)
+    }
+
+    /// A `size` by `size` map of random risk levels.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.extend((0..size.max(1)).map(|_| char::from(b'0' + rng.between(1, 9) as u8)));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+common::register!(15, Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    common::example_tests!();
+
+    #[test]
+    fn generates_risk_levels_from_1_to_9() {
+        let grid = Day15::parse(&DAY.generate(30, 1).unwrap()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (30, 30));
+        assert!(grid.cells().all(|(_, &risk)| (1..=9).contains(&risk)));
+        // Every path takes at least 58 steps, each costing from 1 to 9.
+        assert!((58..=9 * 58).contains(&Day15::part1(&grid).unwrap()));
+    }
+
+    /// The lowest total risk to every position, found by relaxing every position from its
+    /// neighbors until nothing improves.
+    fn relax(grid: &Grid<usize>) -> usize {
+        let mut risk = Grid::from_fn(grid.width(), grid.height(), |_, _| usize::MAX);
+        risk[(0, 0)] = 0;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (x, y) in grid.positions() {
+                for neighbor in grid.neighbors4(x, y) {
+                    if risk[neighbor] != usize::MAX && risk[neighbor] + grid[(x, y)] < risk[(x, y)]
+                    {
+                        risk[(x, y)] = risk[neighbor] + grid[(x, y)];
+                        changed = true;
+                    }
+                }
+            }
+        }
+        risk[(grid.width() - 1, grid.height() - 1)]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn matches_relaxing_every_position(
+            rows in (1..6usize, 1..6usize)
+                .prop_flat_map(|(width, height)| vec(vec(1..=9usize, width), height)),
+        ) {
+            let text = rows
+                .iter()
+                .map(|row| row.iter().map(usize::to_string).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let grid = Day15::parse(&text).unwrap();
+
+            prop_assert_eq!(Day15::part1(&grid).unwrap() as usize, relax(&grid));
+            prop_assert_eq!(Day15::part2(&grid).unwrap() as usize, relax(&expand_grid(&grid, 5)));
+        }
+    }
+}